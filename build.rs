@@ -5,6 +5,7 @@ extern crate serde_derive;
 extern crate serde_json;
 
 use serde_json::Error as JsonError;
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
 use std::io::{Error as IoError, Read, Write};
@@ -17,7 +18,71 @@ pub struct Locale {
 	long_months: Option<Vec<String>>,
 	short_weekdays: Option<Vec<String>>,
 	long_weekdays: Option<Vec<String>>,
+	narrow_months: Option<Vec<String>>,
+	format_short_months: Option<Vec<String>>,
+	format_long_months: Option<Vec<String>>,
+	short_quarters: Option<Vec<String>>,
+	long_quarters: Option<Vec<String>>,
 	ampm: Option<Vec<String>>,
+	hebrew_short_months: Option<Vec<String>>,
+	hebrew_long_months: Option<Vec<String>>,
+	hijri_short_months: Option<Vec<String>>,
+	hijri_long_months: Option<Vec<String>>,
+	persian_short_months: Option<Vec<String>>,
+	persian_long_months: Option<Vec<String>>,
+	chinese_short_months: Option<Vec<String>>,
+	chinese_long_months: Option<Vec<String>>,
+	chinese_days: Option<Vec<String>>,
+	chinese_cyclic_years: Option<Vec<String>>,
+	chinese_zodiacs: Option<Vec<String>>,
+	ordinal_rules: Option<Vec<String>>,
+	ordinal_day_fmt: Option<Vec<String>>,
+	ordinal_day_fmt_feminine: Option<Vec<String>>,
+	date_interval_fmt: Option<Vec<String>>,
+	time_interval_fmt: Option<Vec<String>>,
+	date_formats: Option<Vec<String>>,
+	time_formats: Option<Vec<String>>,
+	date_time_formats: Option<Vec<String>>,
+	available_formats: Option<BTreeMap<String, String>>,
+	zone_standard_names: Option<Vec<String>>,
+	zone_daylight_names: Option<Vec<String>>,
+	zone_generic_names: Option<Vec<String>>,
+	zone_exemplar_cities: Option<BTreeMap<String, String>>,
+	gmt_offset_fmt: Option<Vec<String>>,
+	short_eras: Option<Vec<String>>,
+	narrow_eras: Option<Vec<String>>,
+	hebrew_short_eras: Option<Vec<String>>,
+	hebrew_narrow_eras: Option<Vec<String>>,
+	hijri_short_eras: Option<Vec<String>>,
+	hijri_narrow_eras: Option<Vec<String>>,
+	persian_short_eras: Option<Vec<String>>,
+	persian_narrow_eras: Option<Vec<String>>,
+	buddhist_short_eras: Option<Vec<String>>,
+	buddhist_narrow_eras: Option<Vec<String>>,
+	roc_short_eras: Option<Vec<String>>,
+	roc_narrow_eras: Option<Vec<String>>,
+	japanese_short_eras: Option<Vec<String>>,
+	japanese_narrow_eras: Option<Vec<String>>,
+	d_fmt: Option<String>,
+	t_fmt: Option<String>,
+	d_t_fmt: Option<String>,
+	t_fmt_ampm: Option<String>,
+	era_year_fmt: Option<String>,
+	first_era_year_fmt: Option<String>,
+	chinese_leap_month_fmt: Option<String>,
+	chinese_year_fmt: Option<String>,
+	numbering_system: Option<String>,
+	date_time_interval_fmt: Option<String>,
+	interval_fallback_fmt: Option<String>,
+	gmt_fmt: Option<String>,
+	gmt_zero_fmt: Option<String>,
+}
+
+/// The metazones of `data/metazones.json`, written by `cargo xtask metazones` along with the
+/// version of CLDR they come from.
+#[derive(Deserialize)]
+pub struct Metazones {
+	zones: BTreeMap<String, String>,
 }
 
 fn main() {
@@ -29,13 +94,7 @@ fn main() {
 		r#####"// This file is @generated automatically by chrono_lc. Please don't edit by hand.		
 		lazy_static! {
 		pub static ref LOCALES: Locales = {
-			let mut res = Locales {
-				short_months: HashMap::new(),
-				long_months: HashMap::new(),
-				short_weekdays: HashMap::new(),
-				long_weekdays: HashMap::new(),
-				ampm: HashMap::new(),
-			};
+			let mut res = Locales::default();
 	"#####
 			.as_bytes(),
 	);
@@ -57,78 +116,89 @@ fn main() {
 
 		let locale_name = locale_name.unwrap().to_string();
 		if let Ok(locale_data) = load_locale(&entry) {
-			if let Some(long_months) = locale_data.long_months {
-				if long_months.len() == 12 {
-					f.write_all(
-						format!(
-							"res.long_months.insert(\"{}\".into(), vec![{}]);\n",
-							locale_name,
-							long_months.iter().map(|s| format!("\"{}\"", s)).collect::<Vec<String>>().join(",")
-						)
-						.as_bytes(),
-					)
-					.unwrap();
-				}
-			}
-
-			if let Some(short_months) = locale_data.short_months {
-				if short_months.len() == 12 {
-					f.write_all(
-						format!(
-							"res.short_months.insert(\"{}\".into(), vec![{}]);\n",
-							locale_name,
-							short_months.iter().map(|s| format!("\"{}\"", s)).collect::<Vec<String>>().join(",")
-						)
-						.as_bytes(),
-					)
-					.unwrap();
-				}
-			}
-
-			if let Some(long_weekdays) = locale_data.long_weekdays {
-				if long_weekdays.len() == 7 {
-					f.write_all(
-						format!(
-							"res.long_weekdays.insert(\"{}\".into(), vec![{}]);\n",
-							locale_name,
-							long_weekdays.iter().map(|s| format!("\"{}\"", s)).collect::<Vec<String>>().join(",")
-						)
-						.as_bytes(),
-					)
-					.unwrap();
-				}
-			}
-
-			if let Some(short_weekdays) = locale_data.short_weekdays {
-				if short_weekdays.len() == 7 {
-					f.write_all(
-						format!(
-							"res.short_weekdays.insert(\"{}\".into(), vec![{}]);\n",
-							locale_name,
-							short_weekdays.iter().map(|s| format!("\"{}\"", s)).collect::<Vec<String>>().join(",")
-						)
-						.as_bytes(),
-					)
-					.unwrap();
-				}
-			}
-
-			if let Some(ampm) = locale_data.ampm {
-				if ampm.len() == 4 {
-					f.write_all(
-						format!(
-							"res.ampm.insert(\"{}\".into(), vec![{}]);\n",
-							locale_name,
-							ampm.iter().map(|s| format!("\"{}\"", s)).collect::<Vec<String>>().join(",")
-						)
-						.as_bytes(),
-					)
-					.unwrap();
-				}
-			}
+			f.write_all(format!("res.names.insert({:?}.into());\n", locale_name).as_bytes()).unwrap();
+			write_list(&mut f, "long_months", &locale_name, locale_data.long_months, 12);
+			write_list(&mut f, "short_months", &locale_name, locale_data.short_months, 12);
+			write_list(&mut f, "long_weekdays", &locale_name, locale_data.long_weekdays, 7);
+			write_list(&mut f, "short_weekdays", &locale_name, locale_data.short_weekdays, 7);
+			write_list(&mut f, "long_quarters", &locale_name, locale_data.long_quarters, 4);
+			write_list(&mut f, "narrow_months", &locale_name, locale_data.narrow_months, 12);
+			write_list(&mut f, "format_short_months", &locale_name, locale_data.format_short_months, 12);
+			write_list(&mut f, "format_long_months", &locale_name, locale_data.format_long_months, 12);
+			write_list(&mut f, "short_quarters", &locale_name, locale_data.short_quarters, 4);
+			write_list(&mut f, "ampm", &locale_name, locale_data.ampm, 4);
+			write_list(&mut f, "hebrew_long_months", &locale_name, locale_data.hebrew_long_months, 14);
+			write_list(&mut f, "hebrew_short_months", &locale_name, locale_data.hebrew_short_months, 14);
+			write_list(&mut f, "hijri_long_months", &locale_name, locale_data.hijri_long_months, 12);
+			write_list(&mut f, "hijri_short_months", &locale_name, locale_data.hijri_short_months, 12);
+			write_list(&mut f, "persian_long_months", &locale_name, locale_data.persian_long_months, 12);
+			write_list(&mut f, "persian_short_months", &locale_name, locale_data.persian_short_months, 12);
+			write_list(&mut f, "chinese_long_months", &locale_name, locale_data.chinese_long_months, 12);
+			write_list(&mut f, "chinese_short_months", &locale_name, locale_data.chinese_short_months, 12);
+			write_list(&mut f, "chinese_days", &locale_name, locale_data.chinese_days, 30);
+			write_list(&mut f, "chinese_cyclic_years", &locale_name, locale_data.chinese_cyclic_years, 60);
+			write_list(&mut f, "chinese_zodiacs", &locale_name, locale_data.chinese_zodiacs, 12);
+			write_list(&mut f, "ordinal_rules", &locale_name, locale_data.ordinal_rules, 5);
+			write_list(&mut f, "ordinal_day_fmt", &locale_name, locale_data.ordinal_day_fmt, 6);
+			write_list(&mut f, "ordinal_day_fmt_feminine", &locale_name, locale_data.ordinal_day_fmt_feminine, 6);
+			write_list(&mut f, "date_interval_fmt", &locale_name, locale_data.date_interval_fmt, 4);
+			write_list(&mut f, "time_interval_fmt", &locale_name, locale_data.time_interval_fmt, 4);
+			write_list(&mut f, "date_formats", &locale_name, locale_data.date_formats, 4);
+			write_list(&mut f, "time_formats", &locale_name, locale_data.time_formats, 4);
+			write_list(&mut f, "date_time_formats", &locale_name, locale_data.date_time_formats, 4);
+			write_map(&mut f, "available_formats", &locale_name, locale_data.available_formats);
+			write_list(&mut f, "zone_standard_names", &locale_name, locale_data.zone_standard_names, 40);
+			write_list(&mut f, "zone_daylight_names", &locale_name, locale_data.zone_daylight_names, 40);
+			write_list(&mut f, "zone_generic_names", &locale_name, locale_data.zone_generic_names, 40);
+			write_map(&mut f, "zone_exemplar_cities", &locale_name, locale_data.zone_exemplar_cities);
+			write_list(&mut f, "gmt_offset_fmt", &locale_name, locale_data.gmt_offset_fmt, 2);
+			write_list(&mut f, "short_eras", &locale_name, locale_data.short_eras, 2);
+			write_list(&mut f, "narrow_eras", &locale_name, locale_data.narrow_eras, 2);
+			write_list(&mut f, "hebrew_short_eras", &locale_name, locale_data.hebrew_short_eras, 1);
+			write_list(&mut f, "hebrew_narrow_eras", &locale_name, locale_data.hebrew_narrow_eras, 1);
+			write_list(&mut f, "hijri_short_eras", &locale_name, locale_data.hijri_short_eras, 1);
+			write_list(&mut f, "hijri_narrow_eras", &locale_name, locale_data.hijri_narrow_eras, 1);
+			write_list(&mut f, "persian_short_eras", &locale_name, locale_data.persian_short_eras, 1);
+			write_list(&mut f, "persian_narrow_eras", &locale_name, locale_data.persian_narrow_eras, 1);
+			write_list(&mut f, "buddhist_short_eras", &locale_name, locale_data.buddhist_short_eras, 1);
+			write_list(&mut f, "buddhist_narrow_eras", &locale_name, locale_data.buddhist_narrow_eras, 1);
+			write_list(&mut f, "roc_short_eras", &locale_name, locale_data.roc_short_eras, 2);
+			write_list(&mut f, "roc_narrow_eras", &locale_name, locale_data.roc_narrow_eras, 2);
+			write_list(&mut f, "japanese_short_eras", &locale_name, locale_data.japanese_short_eras, 5);
+			write_list(&mut f, "japanese_narrow_eras", &locale_name, locale_data.japanese_narrow_eras, 5);
+			write_value(&mut f, "d_fmt", &locale_name, locale_data.d_fmt);
+			write_value(&mut f, "t_fmt", &locale_name, locale_data.t_fmt);
+			write_value(&mut f, "d_t_fmt", &locale_name, locale_data.d_t_fmt);
+			write_value(&mut f, "t_fmt_ampm", &locale_name, locale_data.t_fmt_ampm);
+			write_value(&mut f, "era_year_fmt", &locale_name, locale_data.era_year_fmt);
+			write_value(&mut f, "first_era_year_fmt", &locale_name, locale_data.first_era_year_fmt);
+			write_value(&mut f, "chinese_leap_month_fmt", &locale_name, locale_data.chinese_leap_month_fmt);
+			write_value(&mut f, "chinese_year_fmt", &locale_name, locale_data.chinese_year_fmt);
+			write_value(&mut f, "numbering_system", &locale_name, locale_data.numbering_system);
+			write_value(&mut f, "date_time_interval_fmt", &locale_name, locale_data.date_time_interval_fmt);
+			write_value(&mut f, "interval_fallback_fmt", &locale_name, locale_data.interval_fallback_fmt);
+			write_value(&mut f, "gmt_fmt", &locale_name, locale_data.gmt_fmt);
+			write_value(&mut f, "gmt_zero_fmt", &locale_name, locale_data.gmt_zero_fmt);
 		}
 	}
 
+	// the metazone of each time zone, which the names of the zones in the locales are given by
+	if let Ok(metazones) = load_metazones(Path::new("data/metazones.json")) {
+		f.write_all(
+			format!(
+				"res.metazones = vec![{}];\n",
+				metazones
+					.zones
+					.iter()
+					.map(|pair| format!("{:?}", pair))
+					.collect::<Vec<String>>()
+					.join(",")
+			)
+			.as_bytes(),
+		)
+		.unwrap();
+	}
+
 	let _ = f.write_all(
 		r####"		res
 			};
@@ -140,6 +210,48 @@ fn main() {
 	println!("Formatting...");
 }
 
+/// Adds a list of strings to a table, as long as it has the expected number of entries.
+fn write_list(f: &mut File, table: &str, locale_name: &str, list: Option<Vec<String>>, len: usize) {
+	if let Some(list) = list {
+		if list.len() == len {
+			f.write_all(
+				format!(
+					"res.{}.insert({:?}.into(), vec![{}]);\n",
+					table,
+					locale_name,
+					list.iter().map(|s| format!("{:?}", s)).collect::<Vec<String>>().join(",")
+				)
+				.as_bytes(),
+			)
+			.unwrap();
+		}
+	}
+}
+
+/// Adds strings by key to a table, as a list of pairs sorted by key.
+fn write_map(f: &mut File, table: &str, locale_name: &str, map: Option<BTreeMap<String, String>>) {
+	if let Some(map) = map {
+		f.write_all(
+			format!(
+				"res.{}.insert({:?}.into(), vec![{}]);\n",
+				table,
+				locale_name,
+				map.iter().map(|pair| format!("{:?}", pair)).collect::<Vec<String>>().join(",")
+			)
+			.as_bytes(),
+		)
+		.unwrap();
+	}
+}
+
+/// Adds a single string to a table.
+fn write_value(f: &mut File, table: &str, locale_name: &str, value: Option<String>) {
+	if let Some(value) = value {
+		f.write_all(format!("res.{}.insert({:?}.into(), {:?});\n", table, locale_name, value).as_bytes())
+			.unwrap();
+	}
+}
+
 fn load_locale(entry: &DirEntry) -> Result<Locale, BuildError> {
 	let mut locale_data = String::new();
 	let mut f = File::open(entry.path())?;
@@ -148,6 +260,12 @@ fn load_locale(entry: &DirEntry) -> Result<Locale, BuildError> {
 	Ok(locale)
 }
 
+fn load_metazones(path: &Path) -> Result<Metazones, BuildError> {
+	let mut data = String::new();
+	File::open(path)?.read_to_string(&mut data)?;
+	Ok(serde_json::from_str(&data)?)
+}
+
 #[allow(dead_code)]
 enum BuildError {
 	Io(IoError),
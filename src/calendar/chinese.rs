@@ -0,0 +1,167 @@
+//! The Chinese lunisolar calendar.
+//!
+//! Months start on new moons and years on the second new moon after the winter solstice, with a
+//! leap month added when a year has 13 of them. Rather than computing the positions of the sun
+//! and the moon, the calendar is tabulated from 1900 to 2100 after CLDR's data.
+
+use chrono::{Datelike, NaiveDate};
+
+/// The new year of 1900, 31 January.
+const EPOCH: i64 = 693_626;
+const FIRST_YEAR: i32 = 1900;
+
+/// The years from 1900, each with its leap month (0 if none) in bits 13 to 16 and its months of
+/// 30 days in the lower 13 bits, where the least significant one stands for the first month and a
+/// leap month follows the month it repeats.
+const YEARS: [u32; 201] = [
+	0x116D2, 0x00752, 0x00EA5, 0x0B64A, 0x0064B, 0x00A9B, 0x09556, 0x0056A, 0x00B59, 0x05752, 0x00752, 0x0DB25, 0x00B25, 0x00A4B, 0x0B4AB, 0x002AD,
+	0x0056B, 0x06B69, 0x00DA9, 0x0FD92, 0x00E92, 0x00D25, 0x0DA4D, 0x00A56, 0x002B6, 0x095B5, 0x006D4, 0x00EA9, 0x05E92, 0x00E92, 0x0CD26, 0x0052B,
+	0x00A57, 0x0B2B6, 0x00B5A, 0x006D4, 0x06EC9, 0x00749, 0x0F693, 0x00A93, 0x0052B, 0x0CA5B, 0x00AAD, 0x0056A, 0x09B55, 0x00BA4, 0x00B49, 0x05A93,
+	0x00A95, 0x0F52D, 0x00536, 0x00AAD, 0x0B5AA, 0x00DB2, 0x00DA4, 0x07D49, 0x00D4A, 0x10A95, 0x00A97, 0x00556, 0x0CAB5, 0x00AD5, 0x006D2, 0x08EA5,
+	0x00EA5, 0x0064A, 0x06C97, 0x00A9B, 0x0F55A, 0x0056A, 0x00B69, 0x0B752, 0x00B52, 0x00B25, 0x0964B, 0x00A4B, 0x114AB, 0x002AD, 0x0056D, 0x0CB69,
+	0x00DA9, 0x00D92, 0x09D25, 0x00D25, 0x15A4D, 0x00A56, 0x002B6, 0x0E5B5, 0x006D5, 0x00EA9, 0x0BE92, 0x00E92, 0x00D26, 0x06A56, 0x00A57, 0x114D6,
+	0x0035A, 0x006D5, 0x0AEC9, 0x00749, 0x00693, 0x0952B, 0x0052B, 0x00A5B, 0x0555A, 0x0056A, 0x0FB55, 0x00BA4, 0x00B49, 0x0BA93, 0x00A95, 0x0052D,
+	0x08A6D, 0x00AB5, 0x135AA, 0x005D2, 0x00DA5, 0x0DD4A, 0x00E4A, 0x00C95, 0x0952E, 0x00556, 0x00AB5, 0x055B2, 0x006D2, 0x0CEA5, 0x00F25, 0x0064A,
+	0x0AC97, 0x004AB, 0x0055B, 0x06AD6, 0x00B69, 0x17752, 0x00B52, 0x00B25, 0x0DA4B, 0x00A4B, 0x004AB, 0x0A55B, 0x005AD, 0x00B6A, 0x05B52, 0x00D92,
+	0x0FD25, 0x00D25, 0x00A55, 0x0B4AD, 0x004B6, 0x005B5, 0x06DAA, 0x00EC9, 0x11E92, 0x00E92, 0x00D26, 0x0CA56, 0x00A57, 0x004D6, 0x086D5, 0x00755,
+	0x00749, 0x06E93, 0x00693, 0x0F52B, 0x0052B, 0x00A5B, 0x0B55A, 0x0056A, 0x00B65, 0x0974A, 0x00B49, 0x11A95, 0x00A95, 0x0052D, 0x0CAAD, 0x00AB5,
+	0x005AA, 0x08BA5, 0x00DA5, 0x00D4A, 0x07C95, 0x00C96, 0x0F94E, 0x00556, 0x00AB5, 0x0B5B2, 0x006D2, 0x00EA5, 0x08E4A, 0x0068B, 0x10C97, 0x004AB,
+	0x0055B, 0x0CAD6, 0x00B6A, 0x00752, 0x09725, 0x00B45, 0x00A8B, 0x0549B, 0x004AB,
+];
+
+/// The animals of the Chinese zodiac, one for each year in a cycle of 12.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Zodiac {
+	Rat,
+	Ox,
+	Tiger,
+	Rabbit,
+	Dragon,
+	Snake,
+	Horse,
+	Goat,
+	Monkey,
+	Rooster,
+	Dog,
+	Pig,
+}
+
+impl Zodiac {
+	const ALL: [Zodiac; 12] = [
+		Zodiac::Rat,
+		Zodiac::Ox,
+		Zodiac::Tiger,
+		Zodiac::Rabbit,
+		Zodiac::Dragon,
+		Zodiac::Snake,
+		Zodiac::Horse,
+		Zodiac::Goat,
+		Zodiac::Monkey,
+		Zodiac::Rooster,
+		Zodiac::Dog,
+		Zodiac::Pig,
+	];
+
+	/// Returns the animal of the Chinese year starting in a Gregorian year.
+	pub fn from_year(year: i32) -> Zodiac {
+		Zodiac::ALL[(cyclic_year(year) as usize - 1) % 12]
+	}
+
+	/// Returns the index of the animal in the tables of names, starting from 0 for the rat.
+	pub(crate) fn index(self) -> usize {
+		self as usize
+	}
+}
+
+/// A date in the Chinese calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChineseDate {
+	year: i32,
+	month: u32,
+	leap_month: bool,
+	day: u32,
+	ordinal: u32,
+}
+
+impl ChineseDate {
+	/// Converts a date, which must be between the new years of 1900 and 2101.
+	pub fn from_date(date: NaiveDate) -> Option<ChineseDate> {
+		let mut days = i64::from(date.num_days_from_ce()) - EPOCH;
+		if days < 0 {
+			return None;
+		}
+
+		for (year, data) in (FIRST_YEAR..).zip(YEARS) {
+			let leap_month = data >> 13;
+			let months = if leap_month == 0 { 12 } else { 13 };
+			let year_length = 29 * i64::from(months) + i64::from((data & 0x1FFF).count_ones());
+			if days >= year_length {
+				days -= year_length;
+				continue;
+			}
+
+			let ordinal = days as u32 + 1;
+			for position in 0..months {
+				let month_length = 29 + i64::from((data >> position) & 1);
+				if days < month_length {
+					let (month, leap) = match position {
+						_ if leap_month == 0 || position < leap_month => (position + 1, false),
+						_ if position == leap_month => (position, true),
+						_ => (position, false),
+					};
+					return Some(ChineseDate {
+						year,
+						month,
+						leap_month: leap,
+						day: days as u32 + 1,
+						ordinal,
+					});
+				}
+				days -= month_length;
+			}
+		}
+
+		None
+	}
+
+	/// Returns the Gregorian year in which the year starts.
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, from 1 to 12. Leap months have the number of the month they follow.
+	pub fn month(&self) -> u32 {
+		self.month
+	}
+
+	/// Returns whether the month is a leap month.
+	pub fn is_leap_month(&self) -> bool {
+		self.leap_month
+	}
+
+	/// Returns the day of the month, starting from 1.
+	pub fn day(&self) -> u32 {
+		self.day
+	}
+
+	/// Returns the day of the year, starting from 1 for the new year.
+	pub fn ordinal(&self) -> u32 {
+		self.ordinal
+	}
+
+	/// Returns the position of the year in the sexagenary cycle, from 1 (jiazi) to 60 (guihai).
+	pub fn cyclic_year(&self) -> u32 {
+		cyclic_year(self.year)
+	}
+
+	/// Returns the animal of the year.
+	pub fn zodiac(&self) -> Zodiac {
+		Zodiac::from_year(self.year)
+	}
+}
+
+/// Returns the position in the sexagenary cycle of the Chinese year starting in a Gregorian year.
+pub(crate) fn cyclic_year(year: i32) -> u32 {
+	// 1984 started a cycle
+	(year - 1984).rem_euclid(60) as u32 + 1
+}
@@ -0,0 +1,206 @@
+//! The Hebrew (Jewish) calendar.
+//!
+//! Conversions follow the arithmetic rules described in Reingold and Dershowitz's
+//! *Calendrical Calculations*, counting days from the proleptic Gregorian 1 January of year 1.
+
+use chrono::{Datelike, NaiveDate};
+
+/// Day 1 of the Hebrew calendar (1 Tishri of year 1), 7 October 3761 BCE in the Julian calendar.
+const EPOCH: i64 = -1_373_427;
+
+/// Months as numbered by the Bible, starting from Nisan. The year starts in the seventh one.
+const NISAN: u32 = 1;
+const TISHRI: u32 = 7;
+const ADAR: u32 = 12;
+const ADAR_II: u32 = 13;
+
+/// A date in the Hebrew calendar.
+///
+/// Months are counted from the start of the year: Tishri is 1 and Elul is 12, or 13 in leap
+/// years, which have two months of Adar (Adar I is 6 and Adar II is 7).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HebrewDate {
+	year: i32,
+	/// The month in biblical order, which doesn't depend on the year being leap.
+	month: u32,
+	day: u32,
+}
+
+impl HebrewDate {
+	/// Returns the year, counted from the creation of the world (Anno Mundi).
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, starting from 1 for Tishri.
+	pub fn month(&self) -> u32 {
+		if self.month >= TISHRI {
+			self.month - TISHRI + 1
+		} else {
+			self.month + last_month_of_year(self.year) - TISHRI + 1
+		}
+	}
+
+	/// Returns the day of the month, starting from 1.
+	pub fn day(&self) -> u32 {
+		self.day
+	}
+
+	/// Returns the day of the year, starting from 1 for 1 Tishri.
+	pub fn ordinal(&self) -> u32 {
+		(fixed_from_hebrew(self.year, self.month, self.day) - new_year(self.year) + 1) as u32
+	}
+
+	/// Returns whether the year has 13 months.
+	pub fn is_leap_year(&self) -> bool {
+		is_leap_year(self.year)
+	}
+
+	/// Returns the index of the month in the tables of month names, which have 14 entries:
+	/// the 12 months of common years from Tishri to Elul (with Adar I before Adar) and Adar II.
+	pub(crate) fn month_name_index(&self) -> usize {
+		match self.month {
+			ADAR if self.is_leap_year() => 5,
+			ADAR_II => 13,
+			month if month >= TISHRI => (month - TISHRI) as usize + usize::from(month == ADAR),
+			month => (month + 6) as usize,
+		}
+	}
+}
+
+impl From<NaiveDate> for HebrewDate {
+	fn from(date: NaiveDate) -> Self {
+		let date = i64::from(date.num_days_from_ce());
+
+		// average length of a year: 35975351 / 98496 days, which may be a bit too short
+		let approx = ((date - EPOCH) * 98_496).div_euclid(35_975_351) as i32 + 1;
+		let mut year = approx - 1;
+		while new_year(year + 1) <= date {
+			year += 1;
+		}
+
+		let start = if date < fixed_from_hebrew(year, NISAN, 1) { TISHRI } else { NISAN };
+		let month = (start..)
+			.find(|&month| date <= fixed_from_hebrew(year, month, last_day_of_month(year, month)))
+			.expect("a date always falls in a month");
+		let day = (date - fixed_from_hebrew(year, month, 1) + 1) as u32;
+
+		HebrewDate { year, month, day }
+	}
+}
+
+fn is_leap_year(year: i32) -> bool {
+	(7 * i64::from(year) + 1).rem_euclid(19) < 7
+}
+
+fn last_month_of_year(year: i32) -> u32 {
+	if is_leap_year(year) {
+		ADAR_II
+	} else {
+		ADAR
+	}
+}
+
+/// Days between the epoch and the molad (new moon) of Tishri, postponed when it falls on a
+/// Sunday, a Wednesday or a Friday.
+fn elapsed_days(year: i32) -> i64 {
+	let months = (235 * i64::from(year) - 234).div_euclid(19);
+	let parts = 12_084 + 13_753 * months;
+	let days = 29 * months + parts.div_euclid(25_920);
+	if (3 * (days + 1)).rem_euclid(7) < 3 {
+		days + 1
+	} else {
+		days
+	}
+}
+
+/// Delays the new year so that years have a valid length.
+fn new_year_delay(year: i32) -> i64 {
+	let (previous, current, next) = (elapsed_days(year - 1), elapsed_days(year), elapsed_days(year + 1));
+	if next - current == 356 {
+		2
+	} else if current - previous == 382 {
+		1
+	} else {
+		0
+	}
+}
+
+fn new_year(year: i32) -> i64 {
+	EPOCH + elapsed_days(year) + new_year_delay(year)
+}
+
+fn days_in_year(year: i32) -> i64 {
+	new_year(year + 1) - new_year(year)
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+	let short = match month {
+		2 | 4 | 6 | 10 | ADAR_II => true,
+		ADAR => !is_leap_year(year),
+		// Heshvan is long and Kislev short in some years
+		8 => !matches!(days_in_year(year), 355 | 385),
+		9 => matches!(days_in_year(year), 353 | 383),
+		_ => false,
+	};
+	if short {
+		29
+	} else {
+		30
+	}
+}
+
+fn fixed_from_hebrew(year: i32, month: u32, day: u32) -> i64 {
+	let days_before_month = if month < TISHRI {
+		let until_nisan = (TISHRI..=last_month_of_year(year)).map(|m| last_day_of_month(year, m)).sum::<u32>();
+		until_nisan + (NISAN..month).map(|m| last_day_of_month(year, m)).sum::<u32>()
+	} else {
+		(TISHRI..month).map(|m| last_day_of_month(year, m)).sum::<u32>()
+	};
+	new_year(year) + i64::from(days_before_month) + i64::from(day) - 1
+}
+
+/// Writes a number with Hebrew letters, as used for days and years (without the thousands,
+/// e.g. 787 for 5787): 17 is "י״ז" and 5 is "ה׳".
+pub fn numeral(mut n: u32) -> String {
+	const HUNDREDS: [char; 4] = ['ק', 'ר', 'ש', 'ת'];
+	const TENS: [char; 9] = ['י', 'כ', 'ל', 'מ', 'נ', 'ס', 'ע', 'פ', 'צ'];
+	const UNITS: [char; 9] = ['א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט'];
+
+	let mut letters = Vec::new();
+	while n >= 400 {
+		letters.push('ת');
+		n -= 400;
+	}
+	if n >= 100 {
+		letters.push(HUNDREDS[(n / 100) as usize - 1]);
+		n %= 100;
+	}
+	// 15 and 16 are written as 9 + 6 and 9 + 7, not to spell the name of God
+	if n == 15 || n == 16 {
+		letters.push('ט');
+		n -= 9;
+	}
+	if n >= 10 {
+		letters.push(TENS[(n / 10) as usize - 1]);
+		n %= 10;
+	}
+	if n > 0 {
+		letters.push(UNITS[n as usize - 1]);
+	}
+
+	let mut res = String::new();
+	match letters.split_last() {
+		Some((last, [])) => {
+			res.push(*last);
+			res.push('׳');
+		}
+		Some((last, rest)) => {
+			res.extend(rest);
+			res.push('״');
+			res.push(*last);
+		}
+		None => {}
+	}
+	res
+}
@@ -0,0 +1,119 @@
+//! The Islamic (Hijri) calendar, in its arithmetic and Umm al-Qura variants.
+//!
+//! Both have 12 lunar months of 29 or 30 days and count years from the Hijra (16 July 622 in the
+//! Julian calendar). The arithmetic one, known as civil, makes 11 of every 30 years leap years.
+//! The Umm al-Qura calendar of Saudi Arabia is based on astronomical observations, tabulated
+//! here from 1300 to 1600 AH like CLDR does; other dates use the civil calendar.
+
+use chrono::{Datelike, NaiveDate};
+
+/// 1 Muharram 1 AH, a Friday.
+const EPOCH: i64 = 227_015;
+
+/// 1 Muharram 1300 AH, 12 November 1882, where the Umm al-Qura table starts.
+const UMM_AL_QURA_EPOCH: i64 = 687_337;
+const UMM_AL_QURA_FIRST_YEAR: i32 = 1300;
+
+/// The months of 30 days of each year of the Umm al-Qura calendar from 1300 AH, as bit masks
+/// where the least significant bit stands for Muharram.
+const UMM_AL_QURA_MONTHS: [u16; 301] = [
+	0x555, 0x2AB, 0x937, 0x2B6, 0x576, 0x36C, 0xB55, 0xAAA, 0x956, 0x49E, 0x95D, 0x2BA, 0x5B5, 0x3AA, 0xB4B, 0xA96, 0x52E, 0x2AD, 0x56D, 0xB5A,
+	0x752, 0xF25, 0xE8A, 0xD16, 0xA56, 0xAB5, 0x6B4, 0xDA9, 0xB92, 0xB25, 0x64B, 0xA9B, 0x35A, 0x6D9, 0x5D4, 0xDA5, 0xD4A, 0xA95, 0x536, 0x975,
+	0x2F4, 0x6E9, 0x6D4, 0x6A9, 0x535, 0x25D, 0x4BD, 0x9BA, 0x3B4, 0xB69, 0xB2A, 0xA55, 0x4AD, 0xA5D, 0x2DA, 0x6D9, 0xEAA, 0xE94, 0xD2A, 0xC56,
+	0x4AE, 0xA6D, 0x56A, 0xD55, 0xD4A, 0xA93, 0x52B, 0xA5B, 0x53A, 0x6B5, 0xEA9, 0xD52, 0xD29, 0xA55, 0x4AD, 0x56D, 0xAEA, 0x6E4, 0xED1, 0xDA2,
+	0xAAA, 0x95A, 0x2DA, 0x5B9, 0xBB2, 0x764, 0x6C9, 0x555, 0x2AB, 0x4DB, 0xABA, 0x5B4, 0xDA9, 0xD52, 0xAA5, 0x92D, 0x26D, 0x8ED, 0x2DA, 0xAD5,
+	0xAA5, 0xA4B, 0x497, 0x937, 0x2B6, 0x975, 0xD69, 0xD52, 0xC95, 0x92B, 0x25B, 0x4DB, 0x9D5, 0x5D2, 0xDA5, 0xD4A, 0xA95, 0x54D, 0xAAD, 0x3AA,
+	0xBD2, 0xBC4, 0xB89, 0xA95, 0x52D, 0x5AD, 0xB6A, 0x6D4, 0xDC9, 0xD92, 0xAA6, 0x956, 0x2AE, 0x56D, 0x36A, 0xB55, 0xAAA, 0x94D, 0x49D, 0x95D,
+	0x2BA, 0x5B5, 0x5AA, 0xD55, 0xA9A, 0x92E, 0x26E, 0x55D, 0xADA, 0x6D4, 0x6A5, 0xB27, 0xA4D, 0x4AD, 0x56D, 0xB5A, 0x754, 0xF49, 0xE92, 0xD26,
+	0xA56, 0x356, 0x6B5, 0xBAA, 0xB92, 0xB25, 0x68B, 0xA9B, 0x55A, 0xADA, 0x5B4, 0xDA9, 0xB52, 0xA9A, 0x536, 0x276, 0x575, 0xAF2, 0x6D4, 0x6A9,
+	0x555, 0x2AD, 0x4BD, 0x9BA, 0x574, 0xB69, 0xB52, 0xA95, 0x52D, 0xA5D, 0x4DA, 0xAD9, 0x6B2, 0xE95, 0xE2A, 0xC96, 0x92E, 0xAAD, 0x56A, 0xD65,
+	0xD4A, 0xD15, 0x62B, 0xC5B, 0x53A, 0x6B5, 0xDB2, 0xD64, 0xD29, 0xA55, 0x4AD, 0x96D, 0xAEA, 0x6E8, 0xED1, 0xDA4, 0xD4A, 0xA6A, 0x2DA, 0x5B9,
+	0xB72, 0xB68, 0x6D1, 0x655, 0x4AB, 0x95B, 0x2BA, 0x5B5, 0xDA9, 0xD52, 0xCA6, 0x94E, 0x46E, 0x95D, 0x4DA, 0xAD5, 0xAAA, 0xA4D, 0x49B, 0x937,
+	0x4B6, 0x975, 0xD6A, 0xD52, 0xAA5, 0x94B, 0x2AB, 0x55B, 0xAD9, 0x5D2, 0xDC5, 0xD92, 0xB25, 0x555, 0xAB5, 0x5B4, 0xBA9, 0x7A2, 0x745, 0x593,
+	0xAAB, 0x4D6, 0x9D6, 0x5D2, 0xBA5, 0xB4A, 0xA95, 0x4AD, 0x15D, 0x2DD, 0x9DA, 0x5B4, 0x5A9, 0x52D, 0x25B, 0x8B7, 0x176, 0x56D, 0xB6A, 0xACA,
+	0xA96, 0x52B, 0x15B, 0x2BB, 0x5B6, 0xDAA, 0xB94, 0xD46, 0xA8D, 0x52D, 0xA9D, 0x55A, 0x755, 0x749, 0xF13, 0xE4A, 0xA96, 0x556, 0x6B5, 0xBAA,
+	0xB94,
+];
+
+/// A date in the Hijri calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HijriDate {
+	year: i32,
+	month: u32,
+	day: u32,
+	ordinal: u32,
+}
+
+impl HijriDate {
+	/// Converts a date to the arithmetic (civil) calendar.
+	pub fn civil(date: NaiveDate) -> HijriDate {
+		let date = i64::from(date.num_days_from_ce());
+
+		let year = (30 * (date - EPOCH) + 10_646).div_euclid(10_631) as i32;
+		let ordinal = (date - fixed_from_civil(year, 1, 1)) as u32 + 1;
+		let month = (11 * (ordinal - 1) + 330) / 325;
+		let day = (date - fixed_from_civil(year, month, 1)) as u32 + 1;
+
+		HijriDate { year, month, day, ordinal }
+	}
+
+	/// Converts a date to the Umm al-Qura calendar, or to the civil one outside of 1300 to 1600 AH.
+	pub fn umm_al_qura(date: NaiveDate) -> HijriDate {
+		let mut days = i64::from(date.num_days_from_ce()) - UMM_AL_QURA_EPOCH;
+		if days < 0 {
+			return HijriDate::civil(date);
+		}
+
+		for (year, months) in (UMM_AL_QURA_FIRST_YEAR..).zip(UMM_AL_QURA_MONTHS) {
+			let year_length = 12 * 29 + i64::from(months.count_ones());
+			if days >= year_length {
+				days -= year_length;
+				continue;
+			}
+
+			let ordinal = days as u32 + 1;
+			let mut month = 1;
+			loop {
+				let month_length = 29 + i64::from((months >> (month - 1)) & 1);
+				if days < month_length {
+					return HijriDate {
+						year,
+						month,
+						day: days as u32 + 1,
+						ordinal,
+					};
+				}
+				days -= month_length;
+				month += 1;
+			}
+		}
+
+		HijriDate::civil(date)
+	}
+
+	/// Returns the year, counted from the Hijra (Anno Hegirae).
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, starting from 1 for Muharram.
+	pub fn month(&self) -> u32 {
+		self.month
+	}
+
+	/// Returns the day of the month, starting from 1.
+	pub fn day(&self) -> u32 {
+		self.day
+	}
+
+	/// Returns the day of the year, starting from 1 for 1 Muharram.
+	pub fn ordinal(&self) -> u32 {
+		self.ordinal
+	}
+}
+
+fn fixed_from_civil(year: i32, month: u32, day: u32) -> i64 {
+	let year = i64::from(year);
+	let month = i64::from(month);
+	EPOCH - 1 + (year - 1) * 354 + (3 + 11 * year).div_euclid(30) + 29 * (month - 1) + (6 * month - 1) / 11 + i64::from(day)
+}
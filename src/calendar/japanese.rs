@@ -0,0 +1,80 @@
+//! The Japanese imperial calendar.
+//!
+//! It is the Gregorian calendar with years counted from the accession of each emperor. Only the
+//! modern eras are supported, starting from Meiji as CLDR does (8 September 1868).
+
+use chrono::{Datelike, NaiveDate};
+
+/// An era of the Japanese calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Era {
+	Meiji,
+	Taisho,
+	Showa,
+	Heisei,
+	Reiwa,
+}
+
+impl Era {
+	/// The supported eras, from the oldest to the current one.
+	pub const ALL: [Era; 5] = [Era::Meiji, Era::Taisho, Era::Showa, Era::Heisei, Era::Reiwa];
+
+	/// Returns the first day of the era.
+	pub fn start(self) -> NaiveDate {
+		let (year, month, day) = match self {
+			Era::Meiji => (1868, 9, 8),
+			Era::Taisho => (1912, 7, 30),
+			Era::Showa => (1926, 12, 25),
+			Era::Heisei => (1989, 1, 8),
+			Era::Reiwa => (2019, 5, 1),
+		};
+		NaiveDate::from_ymd_opt(year, month, day).expect("eras start on valid dates")
+	}
+
+	/// Returns the index of the era in the tables of era names, starting from 0 for Meiji.
+	pub(crate) fn index(self) -> usize {
+		self as usize
+	}
+}
+
+/// A date in the Japanese calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct JapaneseDate {
+	era: Era,
+	year: i32,
+	month: u32,
+	day: u32,
+}
+
+impl JapaneseDate {
+	/// Converts a date, which must not be older than the Meiji era.
+	pub fn from_date(date: NaiveDate) -> Option<JapaneseDate> {
+		let era = Era::ALL.iter().rev().copied().find(|era| era.start() <= date)?;
+		Some(JapaneseDate {
+			era,
+			year: date.year() - era.start().year() + 1,
+			month: date.month(),
+			day: date.day(),
+		})
+	}
+
+	/// Returns the era.
+	pub fn era(&self) -> Era {
+		self.era
+	}
+
+	/// Returns the year of the era, starting from 1 (gannen) in the year the era started.
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, starting from 1 for January.
+	pub fn month(&self) -> u32 {
+		self.month
+	}
+
+	/// Returns the day of the month, starting from 1.
+	pub fn day(&self) -> u32 {
+		self.day
+	}
+}
@@ -0,0 +1,430 @@
+//! Calendars dates can be formatted in, besides the Gregorian one.
+//!
+//! The calendar is selected with the `ca` Unicode extension of the locale, so that for example
+//! `he-u-ca-hebrew` formats `%d %B %Y` as "י״ז תשרי תשפ״ז". Weekdays, ISO weeks and timestamps
+//! are the same in every calendar.
+//!
+//! The Buddhist and ROC (Minguo) calendars only count years differently from the Gregorian one,
+//! so they don't need a module of their own.
+//!
+//! Other calendars, e.g. fiscal years, can be added by implementing [Calendar] and passing it to
+//! [DelayedFormatL10n::with_calendar](crate::DelayedFormatL10n::with_calendar).
+
+pub mod chinese;
+pub mod hebrew;
+pub mod hijri;
+pub mod japanese;
+pub mod persian;
+
+use std::{borrow::Cow, fmt::Debug};
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::util;
+
+use self::{chinese::ChineseDate, hebrew::HebrewDate, hijri::HijriDate, japanese::JapaneseDate, persian::PersianDate};
+
+/// The fields of a date in a calendar, as formatted by `%Y`, `%m`, `%d` and `%j`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CalendarFields {
+	pub year: i32,
+	/// The month, starting from 1.
+	pub month: u32,
+	/// Whether the month repeats the previous one, as leap months of the Chinese calendar do.
+	pub leap_month: bool,
+	/// The day of the month, starting from 1.
+	pub day: u32,
+	/// The day of the year, starting from 1.
+	pub ordinal: u32,
+}
+
+impl From<NaiveDate> for CalendarFields {
+	/// Returns the fields of a date in the Gregorian calendar.
+	fn from(date: NaiveDate) -> CalendarFields {
+		CalendarFields {
+			year: date.year(),
+			month: date.month(),
+			leap_month: false,
+			day: date.day(),
+			ordinal: date.ordinal(),
+		}
+	}
+}
+
+/// The era of a date, as formatted by `%EC`, `%EN` and `%Ey`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Era {
+	/// The year of the era, starting from 1.
+	pub year: i32,
+	/// Whether years are counted backwards, as they are before Christ.
+	pub backwards: bool,
+	pub name: Cow<'static, str>,
+	pub narrow_name: Cow<'static, str>,
+}
+
+/// A calendar dates can be formatted in.
+///
+/// Only [Calendar::fields] is required: month names default to the Gregorian ones, and eras to
+/// the Christian ones counting the years of the calendar. Locales are given by their normalised
+/// name, e.g. `pt-br`.
+pub trait Calendar: Debug + Send + Sync {
+	/// Converts a date to the calendar.
+	fn fields(&self, date: &NaiveDate) -> CalendarFields;
+
+	/// Returns the name of the month of a date (`%B`, or `%b` when `long` is false), by default the
+	/// Gregorian one, so that fiscal months keep the names of the months they fall in.
+	fn month_name(&self, date: &NaiveDate, locale: &str, long: bool) -> Cow<'static, str> {
+		if long {
+			util::long_month(date.month0() as usize, locale).into()
+		} else {
+			util::short_month(date.month0() as usize, locale).into()
+		}
+	}
+
+	/// Returns the name of the month of a date as written with its day, which is `%B` and `%b` in a
+	/// pattern with a day of the month, e.g. "lipca" rather than "lipiec" in Polish. By default the
+	/// Gregorian one, so calendars naming their months otherwise override it with
+	/// [Calendar::month_name].
+	fn format_month_name(&self, date: &NaiveDate, locale: &str, long: bool) -> Cow<'static, str> {
+		if long {
+			util::format_long_month(date.month0() as usize, locale).into()
+		} else {
+			util::format_short_month(date.month0() as usize, locale).into()
+		}
+	}
+
+	/// Returns the narrow name of the month of a date (`MMMMM` in ICU patterns), by default the
+	/// Gregorian one.
+	fn narrow_month_name(&self, date: &NaiveDate, locale: &str) -> Cow<'static, str> {
+		util::narrow_month(date.month0() as usize, locale).into()
+	}
+
+	/// Returns the era of a date.
+	fn era(&self, date: &NaiveDate, locale: &str) -> Era {
+		christian_era(self.fields(date).year, locale)
+	}
+
+	/// Returns the pattern of the year with its era (`%EY`).
+	fn year_with_era_fmt(&self, _date: &NaiveDate, locale: &str) -> Cow<'static, str> {
+		util::era_year_fmt(locale).into()
+	}
+
+	/// Returns the width `%Y` is padded to.
+	fn year_width(&self) -> usize {
+		4
+	}
+
+	/// Returns the year written out (`%Y` and `%Ey`), if the calendar doesn't write it with digits in the locale.
+	fn year_name(&self, _year: i32, _locale: &str) -> Option<Cow<'static, str>> {
+		None
+	}
+
+	/// Returns the day of the month written out (`%d`), if the calendar doesn't write it with digits in the locale.
+	fn day_name(&self, _date: &NaiveDate, _locale: &str) -> Option<Cow<'static, str>> {
+		None
+	}
+}
+
+/// Returns the Christian era of a year, where 0 is 1 BC.
+fn christian_era(year: i32, locale: &str) -> Era {
+	// there is no year 0: 1 BC is followed by AD 1
+	let (year, index) = if year <= 0 { (1 - year, 0) } else { (year, 1) };
+	Era {
+		year,
+		backwards: index == 0,
+		name: util::short_era(index, locale).into(),
+		narrow_name: util::narrow_era(index, locale).into(),
+	}
+}
+
+/// The calendars dates can be formatted in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) enum CalendarKind {
+	#[default]
+	Gregorian,
+	Buddhist,
+	Chinese,
+	Hebrew,
+	HijriCivil,
+	HijriUmmAlQura,
+	Japanese,
+	Persian,
+	Roc,
+}
+
+impl CalendarKind {
+	/// Returns the calendar with the given CLDR identifier (e.g. `hebrew`), if supported.
+	pub fn from_id(id: &str) -> Option<CalendarKind> {
+		match id {
+			"gregory" => Some(CalendarKind::Gregorian),
+			"buddhist" => Some(CalendarKind::Buddhist),
+			"chinese" => Some(CalendarKind::Chinese),
+			"hebrew" => Some(CalendarKind::Hebrew),
+			"islamic-civil" => Some(CalendarKind::HijriCivil),
+			"islamic-umalqura" => Some(CalendarKind::HijriUmmAlQura),
+			"japanese" => Some(CalendarKind::Japanese),
+			"persian" => Some(CalendarKind::Persian),
+			"roc" => Some(CalendarKind::Roc),
+			_ => None,
+		}
+	}
+}
+
+/// The Buddhist era (BE) starts 543 years before the Christian one.
+pub(crate) const BUDDHIST_YEAR_OFFSET: i32 = 543;
+
+/// Returns the era of a Gregorian year in the ROC calendar, either 0 (before the Republic of China)
+/// or 1 (Minguo, from 1912), and the year of the era.
+pub(crate) fn roc_era(year: i32) -> (usize, i32) {
+	if year >= 1912 {
+		(1, year - 1911)
+	} else {
+		(0, 1912 - year)
+	}
+}
+
+impl CalendarKind {
+	/// Converts a date to the variant of the Hijri calendar.
+	fn hijri_date(self, date: &NaiveDate) -> HijriDate {
+		if self == CalendarKind::HijriUmmAlQura {
+			HijriDate::umm_al_qura(*date)
+		} else {
+			HijriDate::civil(*date)
+		}
+	}
+
+	/// Converts a date to the Chinese calendar, if it is the one and the date is in the supported range.
+	fn chinese_date(self, date: &NaiveDate) -> Option<ChineseDate> {
+		if self == CalendarKind::Chinese {
+			ChineseDate::from_date(*date)
+		} else {
+			None
+		}
+	}
+
+	/// Returns whether days and years are written with letters, as they are in the Hebrew calendar in Hebrew.
+	fn hebrew_numerals(self, locale: &str) -> bool {
+		self == CalendarKind::Hebrew && locale.split('-').next() == Some("he")
+	}
+}
+
+impl Calendar for CalendarKind {
+	fn fields(&self, date: &NaiveDate) -> CalendarFields {
+		let gregorian = CalendarFields::from(*date);
+		match self {
+			CalendarKind::Gregorian => gregorian,
+			CalendarKind::Buddhist => CalendarFields {
+				year: date.year() + BUDDHIST_YEAR_OFFSET,
+				..gregorian
+			},
+			// dates out of the supported range are written in the Gregorian calendar
+			CalendarKind::Chinese => match ChineseDate::from_date(*date) {
+				Some(date) => CalendarFields {
+					year: date.year(),
+					month: date.month(),
+					leap_month: date.is_leap_month(),
+					day: date.day(),
+					ordinal: date.ordinal(),
+				},
+				None => gregorian,
+			},
+			CalendarKind::Hebrew => {
+				let date = HebrewDate::from(*date);
+				CalendarFields {
+					year: date.year(),
+					month: date.month(),
+					leap_month: false,
+					day: date.day(),
+					ordinal: date.ordinal(),
+				}
+			}
+			CalendarKind::HijriCivil | CalendarKind::HijriUmmAlQura => {
+				let date = self.hijri_date(date);
+				CalendarFields {
+					year: date.year(),
+					month: date.month(),
+					leap_month: false,
+					day: date.day(),
+					ordinal: date.ordinal(),
+				}
+			}
+			CalendarKind::Japanese => CalendarFields {
+				year: JapaneseDate::from_date(*date).map_or(date.year(), |date| date.year()),
+				..gregorian
+			},
+			CalendarKind::Persian => {
+				let date = PersianDate::from(*date);
+				CalendarFields {
+					year: date.year(),
+					month: date.month(),
+					leap_month: false,
+					day: date.day(),
+					ordinal: date.ordinal(),
+				}
+			}
+			CalendarKind::Roc => CalendarFields {
+				year: roc_era(date.year()).1,
+				..gregorian
+			},
+		}
+	}
+
+	fn month_name(&self, date: &NaiveDate, locale: &str, long: bool) -> Cow<'static, str> {
+		if let Some(date) = self.chinese_date(date) {
+			let index = date.month() as usize - 1;
+			let name = if long {
+				util::chinese_long_month(index, locale)
+			} else {
+				util::chinese_short_month(index, locale)
+			};
+			// leap months are named after the month they repeat, e.g. 闰二月
+			return if date.is_leap_month() {
+				util::chinese_leap_month_fmt(locale).replace("{0}", name).into()
+			} else {
+				name.into()
+			};
+		}
+
+		let name = match (self, long) {
+			// Chinese dates out of the supported range are written in the Gregorian calendar
+			(CalendarKind::Gregorian | CalendarKind::Buddhist | CalendarKind::Chinese | CalendarKind::Japanese | CalendarKind::Roc, false) => {
+				util::short_month(date.month0() as usize, locale)
+			}
+			(CalendarKind::Gregorian | CalendarKind::Buddhist | CalendarKind::Chinese | CalendarKind::Japanese | CalendarKind::Roc, true) => {
+				util::long_month(date.month0() as usize, locale)
+			}
+			(CalendarKind::Hebrew, false) => util::hebrew_short_month(HebrewDate::from(*date).month_name_index(), locale),
+			(CalendarKind::Hebrew, true) => util::hebrew_long_month(HebrewDate::from(*date).month_name_index(), locale),
+			(CalendarKind::HijriCivil | CalendarKind::HijriUmmAlQura, false) => {
+				util::hijri_short_month(self.hijri_date(date).month() as usize - 1, locale)
+			}
+			(CalendarKind::HijriCivil | CalendarKind::HijriUmmAlQura, true) => {
+				util::hijri_long_month(self.hijri_date(date).month() as usize - 1, locale)
+			}
+			(CalendarKind::Persian, false) => util::persian_short_month(PersianDate::from(*date).month() as usize - 1, locale),
+			(CalendarKind::Persian, true) => util::persian_long_month(PersianDate::from(*date).month() as usize - 1, locale),
+		};
+		name.into()
+	}
+
+	fn format_month_name(&self, date: &NaiveDate, locale: &str, long: bool) -> Cow<'static, str> {
+		match (self, long) {
+			// the locale data has the names of the other calendars' months in one context only
+			(CalendarKind::Chinese, _) if self.chinese_date(date).is_some() => self.month_name(date, locale, long),
+			(CalendarKind::Gregorian | CalendarKind::Buddhist | CalendarKind::Chinese | CalendarKind::Japanese | CalendarKind::Roc, false) => {
+				util::format_short_month(date.month0() as usize, locale).into()
+			}
+			(CalendarKind::Gregorian | CalendarKind::Buddhist | CalendarKind::Chinese | CalendarKind::Japanese | CalendarKind::Roc, true) => {
+				util::format_long_month(date.month0() as usize, locale).into()
+			}
+			_ => self.month_name(date, locale, long),
+		}
+	}
+
+	fn narrow_month_name(&self, date: &NaiveDate, locale: &str) -> Cow<'static, str> {
+		match self {
+			// Chinese dates out of the supported range are written in the Gregorian calendar
+			CalendarKind::Chinese if self.chinese_date(date).is_some() => self.month_name(date, locale, false),
+			CalendarKind::Gregorian | CalendarKind::Buddhist | CalendarKind::Chinese | CalendarKind::Japanese | CalendarKind::Roc => {
+				util::narrow_month(date.month0() as usize, locale).into()
+			}
+			// the locale data has no narrow names of the other calendars' months
+			_ => self.month_name(date, locale, false),
+		}
+	}
+
+	fn era(&self, date: &NaiveDate, locale: &str) -> Era {
+		let era = |year, index, short: fn(usize, &str) -> &'static str, narrow: fn(usize, &str) -> &'static str| Era {
+			year,
+			backwards: false,
+			name: short(index, locale).into(),
+			narrow_name: narrow(index, locale).into(),
+		};
+
+		match self {
+			CalendarKind::Gregorian => christian_era(date.year(), locale),
+			CalendarKind::Buddhist => era(date.year() + BUDDHIST_YEAR_OFFSET, 0, util::buddhist_short_era, util::buddhist_narrow_era),
+			// years are named after their place in the sexagenary cycle rather than counted in eras
+			CalendarKind::Chinese => match ChineseDate::from_date(*date) {
+				Some(date) => {
+					let cyclic_year = date.cyclic_year();
+					era(
+						cyclic_year as i32,
+						cyclic_year as usize - 1,
+						util::chinese_cyclic_year,
+						util::chinese_cyclic_year,
+					)
+				}
+				None => christian_era(date.year(), locale),
+			},
+			CalendarKind::Hebrew => era(HebrewDate::from(*date).year(), 0, util::hebrew_short_era, util::hebrew_narrow_era),
+			CalendarKind::HijriCivil | CalendarKind::HijriUmmAlQura => {
+				era(self.hijri_date(date).year(), 0, util::hijri_short_era, util::hijri_narrow_era)
+			}
+			// dates older than Meiji are written with the Christian eras
+			CalendarKind::Japanese => match JapaneseDate::from_date(*date) {
+				Some(date) => era(date.year(), date.era().index(), util::japanese_short_era, util::japanese_narrow_era),
+				None => christian_era(date.year(), locale),
+			},
+			CalendarKind::Persian => era(PersianDate::from(*date).year(), 0, util::persian_short_era, util::persian_narrow_era),
+			CalendarKind::Roc => {
+				let (index, year) = roc_era(date.year());
+				Era {
+					backwards: index == 0,
+					..era(year, index, util::roc_short_era, util::roc_narrow_era)
+				}
+			}
+		}
+	}
+
+	fn year_with_era_fmt(&self, date: &NaiveDate, locale: &str) -> Cow<'static, str> {
+		if self.chinese_date(date).is_some() {
+			return util::chinese_year_fmt(locale).into();
+		}
+		// the first year of the Japanese imperial eras and of Minguo is 元年, but not the first year
+		// of other eras nor the years counted backwards
+		let imperial = match self {
+			CalendarKind::Japanese => JapaneseDate::from_date(*date).is_some(),
+			CalendarKind::Roc => true,
+			_ => false,
+		};
+		let era = self.era(date, locale);
+		match util::first_era_year_fmt(locale) {
+			Some(pattern) if imperial && era.year == 1 && !era.backwards => pattern.into(),
+			_ => util::era_year_fmt(locale).into(),
+		}
+	}
+
+	fn year_width(&self) -> usize {
+		// years of eras such as Heisei or Minguo are short, and never padded
+		match self {
+			CalendarKind::Japanese | CalendarKind::Roc => 1,
+			_ => 4,
+		}
+	}
+
+	fn year_name(&self, year: i32, locale: &str) -> Option<Cow<'static, str>> {
+		// years before the creation are written with digits
+		if !self.hebrew_numerals(locale) || year <= 0 {
+			return None;
+		}
+		// the thousands are left out, e.g. תשפ״ז for 5787, unless there are only thousands, e.g. ה׳
+		// for 5000
+		let year = year as u32;
+		let n = match year % 1000 {
+			0 => year / 1000,
+			n => n,
+		};
+		Some(hebrew::numeral(n).into())
+	}
+
+	fn day_name(&self, date: &NaiveDate, locale: &str) -> Option<Cow<'static, str>> {
+		if self.hebrew_numerals(locale) {
+			return Some(hebrew::numeral(HebrewDate::from(*date).day()).into());
+		}
+		// days of the Chinese calendar are named in Chinese, e.g. 初一
+		self.chinese_date(date)
+			.and_then(|date| util::chinese_day(date.day() as usize - 1, locale))
+			.map(Cow::from)
+	}
+}
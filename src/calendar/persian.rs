@@ -0,0 +1,99 @@
+//! The Persian (Solar Hijri, or Jalali) calendar.
+//!
+//! Years start at the vernal equinox and are counted from the Hijra. Their first six months have
+//! 31 days, the next five 30 and Esfand 29, or 30 in leap years. Leap years follow the 33-year
+//! cycle used by CLDR, with its corrections to stay in line with the equinox after 1502 AP.
+
+use chrono::{Datelike, NaiveDate};
+
+/// 1 Farvardin 1 AP.
+const EPOCH: i64 = 226_895;
+
+/// Years that the 33-year cycle makes leap but whose leap day is moved to the following year.
+const NON_LEAP_CORRECTIONS: [i32; 78] = [
+	1502, 1601, 1634, 1667, 1700, 1733, 1766, 1799, 1832, 1865, 1898, 1931, 1964, 1997, 2030, 2059, 2063, 2096, 2129, 2158, 2162, 2191, 2195, 2224,
+	2228, 2257, 2261, 2290, 2294, 2323, 2327, 2356, 2360, 2389, 2393, 2422, 2426, 2455, 2459, 2488, 2492, 2521, 2525, 2554, 2558, 2587, 2591, 2620,
+	2624, 2653, 2657, 2686, 2690, 2719, 2723, 2748, 2752, 2756, 2781, 2785, 2789, 2818, 2822, 2847, 2851, 2855, 2880, 2884, 2888, 2913, 2917, 2921,
+	2946, 2950, 2954, 2979, 2983, 2987,
+];
+
+/// A date in the Persian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersianDate {
+	year: i32,
+	month: u32,
+	day: u32,
+	ordinal: u32,
+}
+
+impl PersianDate {
+	/// Returns the year, counted from the Hijra (Anno Persico).
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, starting from 1 for Farvardin.
+	pub fn month(&self) -> u32 {
+		self.month
+	}
+
+	/// Returns the day of the month, starting from 1.
+	pub fn day(&self) -> u32 {
+		self.day
+	}
+
+	/// Returns the day of the year, starting from 1 for 1 Farvardin (Nowruz).
+	pub fn ordinal(&self) -> u32 {
+		self.ordinal
+	}
+
+	/// Returns whether Esfand has 30 days.
+	pub fn is_leap_year(&self) -> bool {
+		is_leap_year(self.year)
+	}
+}
+
+impl From<NaiveDate> for PersianDate {
+	fn from(date: NaiveDate) -> Self {
+		let date = i64::from(date.num_days_from_ce());
+
+		// the corrections only ever make years start a day earlier
+		let mut year = (33 * (date - EPOCH) + 3).div_euclid(12_053) as i32 + 1;
+		if date >= new_year(year + 1) {
+			year += 1;
+		}
+
+		let days = (date - new_year(year)) as u32;
+		let month = if days < 6 * 31 { days / 31 } else { (days - 6) / 30 };
+		let day = days - if month < 6 { 31 * month } else { 30 * month + 6 } + 1;
+
+		PersianDate {
+			year,
+			month: month + 1,
+			day,
+			ordinal: days + 1,
+		}
+	}
+}
+
+fn is_corrected(year: i32) -> bool {
+	NON_LEAP_CORRECTIONS.binary_search(&year).is_ok()
+}
+
+fn is_leap_year(year: i32) -> bool {
+	if is_corrected(year) {
+		false
+	} else {
+		is_corrected(year - 1) || (25 * i64::from(year) + 11).rem_euclid(33) < 8
+	}
+}
+
+fn new_year(year: i32) -> i64 {
+	let year_i64 = i64::from(year);
+	let days = EPOCH + 365 * (year_i64 - 1) + (8 * year_i64 + 21).div_euclid(33);
+	if is_corrected(year - 1) {
+		days - 1
+	} else {
+		days
+	}
+}
@@ -0,0 +1,39 @@
+//! Formatting of any type with the fields of a date and time, read through chrono's `Datelike` and
+//! `Timelike`, e.g. the timestamps wrapping chrono's types in an application.
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+use crate::{items::StrftimeItemsL10n, DelayedFormatL10n, LocaleDate, ZoneOffset};
+
+/// Formats the fields of a date and time, as [LocaleDate::formatl] formats a `NaiveDateTime`, or
+/// returns `None` if they aren't a valid date and time, e.g. February 30.
+///
+/// The offset names the zone as the offset of a `DateTime` does (see
+/// [DelayedFormatL10n::with_zone]); without one, the specifiers of the offset (e.g. `%z`) can't be
+/// formatted. The year, month, day, hour, minute, second and nanosecond are read once, and the
+/// other fields (e.g. the weekday) are computed from them.
+pub fn formatl_datelike<'a, T, O>(value: &T, fmt: &'a str, locale: &str, offset: Option<&O>) -> Option<DelayedFormatL10n<StrftimeItemsL10n<'a>>>
+where
+	T: Datelike + Timelike,
+	O: ZoneOffset,
+{
+	let format = to_naive_date_time(value)?.formatl(fmt, locale);
+	Some(match offset {
+		Some(offset) => format.with_zone(offset),
+		None => format,
+	})
+}
+
+/// Reads the fields of a date and time into a `NaiveDateTime`, or returns `None` if they aren't a
+/// valid date and time.
+pub fn to_naive_date_time<T: Datelike + Timelike>(value: &T) -> Option<NaiveDateTime> {
+	Some(naive_date(value)?.and_time(naive_time(value)?))
+}
+
+pub(crate) fn naive_date<D: Datelike>(date: &D) -> Option<NaiveDate> {
+	NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())
+}
+
+pub(crate) fn naive_time<T: Timelike>(time: &T) -> Option<NaiveTime> {
+	NaiveTime::from_hms_nano_opt(time.hour(), time.minute(), time.second(), time.nanosecond())
+}
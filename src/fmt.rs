@@ -1,30 +1,57 @@
-use std::fmt;
+use std::{
+	borrow::Cow,
+	fmt::{self, Write},
+};
 
-use crate::util;
+use crate::{
+	calendar::{
+		chinese::{self, ChineseDate},
+		Calendar,
+	},
+	datelike,
+	items::{FixedL10n, ItemL10n, StrftimeItemsL10n},
+	locale::Locale,
+	numbering::{NativeDigits, NumberingSystem},
+	plural::PluralCategory,
+	util,
+	zone::Zone,
+};
 use chrono::{
 	format::{Fixed, Item, Numeric, Pad},
 	Datelike, FixedOffset, NaiveDate, NaiveTime, Timelike,
 };
 use num_integer::{div_floor, mod_floor};
 
-/// Parses a [Fixed] value and formats it.
+/// Parses a [Fixed] value and formats it. Months are named as written with a day if `with_day` is
+/// true, and on their own otherwise.
+#[allow(clippy::too_many_arguments)]
 pub fn parse_fixed(
-	w: &mut fmt::Formatter,
+	w: &mut dyn fmt::Write,
 	date: Option<&NaiveDate>,
 	time: Option<&NaiveTime>,
 	off: Option<&(String, FixedOffset)>,
 	spec: &Fixed,
-	locale: &str,
+	locale: &Locale,
+	calendar: &dyn Calendar,
+	with_day: bool,
 ) -> Option<fmt::Result> {
 	use self::Fixed::*;
 
+	let locale_name = locale.name.as_str();
+	let month_name = |d: &NaiveDate, long| {
+		if with_day {
+			calendar.format_month_name(d, locale_name, long)
+		} else {
+			calendar.month_name(d, locale_name, long)
+		}
+	};
 	match spec {
-		ShortMonthName => date.map(|d| write!(w, "{}", util::short_month(d.month0() as usize, locale))),
-		LongMonthName => date.map(|d| write!(w, "{}", util::long_month(d.month0() as usize, locale))),
-		ShortWeekdayName => date.map(|d| write!(w, "{}", util::short_weekday(d.weekday().num_days_from_monday() as usize, locale))),
-		LongWeekdayName => date.map(|d| write!(w, "{}", util::long_weekday(d.weekday().num_days_from_monday() as usize, locale))),
-		LowerAmPm => time.map(|t| write!(w, "{}", util::ampm(t.hour12().0 as usize, locale))),
-		UpperAmPm => time.map(|t| write!(w, "{}", util::ampm(t.hour12().0 as usize + 2, locale))),
+		ShortMonthName => date.map(|d| write!(w, "{}", month_name(d, false))),
+		LongMonthName => date.map(|d| write!(w, "{}", month_name(d, true))),
+		ShortWeekdayName => date.map(|d| write!(w, "{}", util::short_weekday(d.weekday().num_days_from_monday() as usize, locale_name))),
+		LongWeekdayName => date.map(|d| write!(w, "{}", util::long_weekday(d.weekday().num_days_from_monday() as usize, locale_name))),
+		LowerAmPm => time.map(|t| write!(w, "{}", util::ampm(t.hour12().0 as usize, locale_name))),
+		UpperAmPm => time.map(|t| write!(w, "{}", util::ampm(t.hour12().0 as usize + 2, locale_name))),
 		Nanosecond => time.map(|t| {
 			let nano = t.nanosecond() % 1_000_000_000;
 			if nano == 0 {
@@ -65,9 +92,9 @@ pub fn parse_fixed(
 				write!(
 					w,
 					"{}, {:2} {} {:04} {:02}:{:02}:{:02} ",
-					util::short_weekday(d.weekday().num_days_from_monday() as usize, locale),
+					util::short_weekday(d.weekday().num_days_from_monday() as usize, locale_name),
 					d.day(),
-					util::short_month(d.month0() as usize, locale),
+					util::short_month(d.month0() as usize, locale_name),
 					d.year(),
 					t.hour(),
 					t.minute(),
@@ -96,33 +123,314 @@ pub fn parse_fixed(
 	}
 }
 
+/// Formats a [FixedL10n] value, mostly by formatting the pattern the locale defines for it.
+#[allow(clippy::too_many_arguments)]
+pub fn parse_fixed_l10n(
+	w: &mut fmt::Formatter,
+	date: Option<&NaiveDate>,
+	time: Option<&NaiveTime>,
+	off: Option<&(String, FixedOffset)>,
+	zone: Option<&Zone>,
+	spec: &FixedL10n,
+	locale: &Locale,
+	calendar: &dyn Calendar,
+	depth: usize,
+) -> fmt::Result {
+	use self::FixedL10n::*;
+
+	// patterns may refer to each other (e.g. `%r` in `d_t_fmt`), but never that deep
+	if depth >= MAX_PATTERN_DEPTH {
+		return Err(fmt::Error);
+	}
+
+	let pattern: Cow<'static, str> = match spec {
+		DateTime => util::d_t_fmt(&locale.name).into(),
+		Date => util::d_fmt(&locale.name).into(),
+		Time => util::t_fmt(&locale.name).into(),
+		Time12 => util::t_fmt_ampm(&locale.name).into(),
+		StyledDate(style) => util::date_format(style.index(), &locale.name).into(),
+		StyledTime(style) => util::time_format(style.index(), &locale.name).into(),
+		StyledDateTime(date_style, time_style) => {
+			return write_placeholders(w, util::date_time_format(date_style.index(), &locale.name), |w, index| {
+				let spec = if index == 0 { StyledTime(*time_style) } else { StyledDate(*date_style) };
+				parse_fixed_l10n(w, date, time, off, zone, &spec, locale, calendar, depth + 1)
+			});
+		}
+		YearWithEra => calendar.year_with_era_fmt(date.ok_or(fmt::Error)?, &locale.name),
+		OrdinalDay | FeminineOrdinalDay => {
+			let day = calendar.fields(date.ok_or(fmt::Error)?).day;
+			let category = PluralCategory::of(u64::from(day), |key| util::ordinal_rule(key, &locale.name));
+			match spec {
+				OrdinalDay => util::ordinal_day_fmt(category.index(), &locale.name).into(),
+				_ => util::ordinal_day_fmt_feminine(category.index(), &locale.name).into(),
+			}
+		}
+		Zodiac => {
+			// the animal of the Chinese year, whichever the calendar of the locale
+			let date = date.ok_or(fmt::Error)?;
+			let year = ChineseDate::from_date(*date).map_or(date.year(), |date| date.year());
+			return write!(w, "{}", util::chinese_zodiac(chinese::Zodiac::from_year(year).index(), &locale.name));
+		}
+		ZoneName | GenericZoneName | ZoneCity => {
+			off.ok_or(fmt::Error)?;
+			let name = zone.and_then(|zone| match spec {
+				ZoneName => zone.specific_name(&locale.name).map(Cow::Borrowed),
+				GenericZoneName => zone.generic_name(&locale.name).map(Cow::Borrowed),
+				_ => Some(zone.exemplar_city(&locale.name)),
+			});
+			return match name {
+				Some(name) => write!(w, "{}", name),
+				// zones without names are written in the localized GMT format, as CLDR does
+				None => parse_fixed_l10n(w, date, time, off, zone, &LongGmtOffset, locale, calendar, depth + 1),
+			};
+		}
+		GmtOffset | LongGmtOffset => {
+			let offset = off.ok_or(fmt::Error)?.1.local_minus_utc();
+			if offset == 0 {
+				return write!(w, "{}", util::gmt_zero_fmt(&locale.name));
+			}
+
+			// the hours and minutes of the offset are formatted as a time, e.g. with `+%H:%M`
+			let (sign, offset) = if offset < 0 { (1, -offset) } else { (0, offset) };
+			let offset = NaiveTime::from_num_seconds_from_midnight_opt(offset as u32, 0).ok_or(fmt::Error)?;
+			let mut pattern = util::gmt_offset_fmt(sign, &locale.name).to_owned();
+			if *spec == GmtOffset {
+				// unpadded hours, followed by the minutes only if there are some
+				pattern = pattern.replace("%H", "%-H");
+				if let (0, Some(minutes)) = (offset.minute(), pattern.find("%M")) {
+					let separator = pattern[..minutes].char_indices().next_back().map_or(minutes, |(i, _)| i);
+					pattern.replace_range(separator..minutes + 2, "");
+				}
+			}
+
+			let (prefix, suffix) = util::gmt_fmt(&locale.name).split_once("{0}").ok_or(fmt::Error)?;
+			write!(w, "{}", prefix)?;
+			format_items(
+				w,
+				None,
+				Some(&offset),
+				None,
+				None,
+				StrftimeItemsL10n::new(&pattern),
+				locale,
+				calendar,
+				depth + 1,
+			)?;
+			return write!(w, "{}", suffix);
+		}
+		ShortQuarterName | LongQuarterName => {
+			let quarter = quarter(calendar, date.ok_or(fmt::Error)?) as usize - 1;
+			let name = match spec {
+				ShortQuarterName => util::short_quarter(quarter, &locale.name),
+				_ => util::long_quarter(quarter, &locale.name),
+			};
+			return write!(w, "{}", name);
+		}
+		Hour0To11 { padded } | Hour1To24 { padded } => {
+			let hour = time.ok_or(fmt::Error)?.hour();
+			let hour = if let Hour0To11 { .. } = spec { hour % 12 } else { (hour + 23) % 24 + 1 };
+			let width = if *padded { 2 } else { 1 };
+			return write!(NativeDigits::new(w, locale.numbering), "{:01$}", hour, width);
+		}
+		SecondFraction(digits) => {
+			let nano = time.ok_or(fmt::Error)?.nanosecond() % 1_000_000_000;
+			let fraction = format!("{:09}", nano);
+			let fraction = &fraction[..(*digits).min(9)];
+			return write!(NativeDigits::new(w, locale.numbering), "{:0<1$}", fraction, digits);
+		}
+		EraName => return write!(w, "{}", calendar.era(date.ok_or(fmt::Error)?, &locale.name).name),
+		NarrowEraName => return write!(w, "{}", calendar.era(date.ok_or(fmt::Error)?, &locale.name).narrow_name),
+		YearOfEra | PaddedYearOfEra(_) => {
+			let year = calendar.era(date.ok_or(fmt::Error)?, &locale.name).year;
+			let width = if let PaddedYearOfEra(width) = spec { *width } else { 1 };
+			return match calendar.year_name(year, &locale.name) {
+				Some(name) => write!(w, "{}", name),
+				None => write!(NativeDigits::new(w, locale.numbering), "{:01$}", year, width),
+			};
+		}
+		NarrowMonthName => return write!(w, "{}", calendar.narrow_month_name(date.ok_or(fmt::Error)?, &locale.name)),
+		PaddedQuarter => {
+			return write!(
+				NativeDigits::new(w, locale.numbering),
+				"{:02}",
+				quarter(calendar, date.ok_or(fmt::Error)?)
+			)
+		}
+		PaddedOrdinal(width) => {
+			let ordinal = calendar.fields(date.ok_or(fmt::Error)?).ordinal;
+			return write!(NativeDigits::new(w, locale.numbering), "{:01$}", ordinal, width);
+		}
+	};
+	format_items(w, date, time, off, zone, pattern_items(&pattern, off), locale, calendar, depth + 1)
+}
+
+const MAX_PATTERN_DEPTH: usize = 4;
+
+/// Returns the items of a pattern of the locale data. Dates without an offset are written without
+/// the zone the pattern names, along with the space and brackets around it, e.g. `%H:%M:%S (%EZ)`
+/// is written as `%H:%M:%S`.
+fn pattern_items<'a>(pattern: &'a str, off: Option<&(String, FixedOffset)>) -> std::vec::IntoIter<ItemL10n<'a>> {
+	let mut items: Vec<ItemL10n> = StrftimeItemsL10n::new(pattern).collect();
+	if off.is_some() {
+		return items.into_iter();
+	}
+
+	let only = |item: &ItemL10n, chars: &str| match item {
+		ItemL10n::Chrono(Item::Literal(s) | Item::Space(s)) => s.chars().all(|c| c.is_whitespace() || chars.contains(c)),
+		_ => false,
+	};
+	while let Some(index) = items.iter().position(is_zone_item) {
+		let (mut start, mut end) = (index, index + 1);
+		if end < items.len() && only(&items[end], ")]") {
+			end += 1;
+		}
+		while start > 0 && only(&items[start - 1], "([") {
+			start -= 1;
+		}
+		if start == 0 {
+			while end < items.len() && only(&items[end], "") {
+				end += 1;
+			}
+		}
+		items.drain(start..end);
+	}
+	items.into_iter()
+}
+
+/// Returns whether an item writes the offset or the time zone.
+fn is_zone_item(item: &ItemL10n) -> bool {
+	use chrono::format::Fixed::*;
+	match item {
+		ItemL10n::Chrono(Item::Fixed(spec)) => matches!(
+			spec,
+			TimezoneName
+				| TimezoneOffset
+				| TimezoneOffsetColon
+				| TimezoneOffsetDoubleColon
+				| TimezoneOffsetTripleColon
+				| TimezoneOffsetColonZ
+				| TimezoneOffsetZ
+		),
+		ItemL10n::Fixed(spec) => matches!(
+			spec,
+			FixedL10n::ZoneName | FixedL10n::GenericZoneName | FixedL10n::ZoneCity | FixedL10n::GmtOffset | FixedL10n::LongGmtOffset
+		),
+		_ => false,
+	}
+}
+
+/// Returns whether an item writes the day of the month, so that the months of the same pattern are
+/// named in the format context, e.g. "8 lipca 2001" but "lipiec 2001" in Polish.
+fn is_day_item(item: &ItemL10n) -> bool {
+	matches!(
+		item,
+		ItemL10n::Chrono(Item::Numeric(Numeric::Day, _)) | ItemL10n::Fixed(FixedL10n::OrdinalDay | FixedL10n::FeminineOrdinalDay)
+	)
+}
+
+/// Returns whether an item can be formatted, which [parse_fixed] and [parse_numeric] don't do for
+/// chrono's internal items (e.g. `%3f`), the items chrono adds after them and errors.
+pub fn is_supported(item: &ItemL10n) -> bool {
+	match item {
+		ItemL10n::Chrono(Item::Fixed(spec)) => matches!(
+			spec,
+			Fixed::ShortMonthName
+				| Fixed::LongMonthName
+				| Fixed::ShortWeekdayName
+				| Fixed::LongWeekdayName
+				| Fixed::LowerAmPm
+				| Fixed::UpperAmPm
+				| Fixed::Nanosecond
+				| Fixed::Nanosecond3
+				| Fixed::Nanosecond6
+				| Fixed::Nanosecond9
+				| Fixed::TimezoneName
+				| Fixed::TimezoneOffsetColon
+				| Fixed::TimezoneOffsetColonZ
+				| Fixed::TimezoneOffsetDoubleColon
+				| Fixed::TimezoneOffsetTripleColon
+				| Fixed::TimezoneOffset
+				| Fixed::TimezoneOffsetZ
+				| Fixed::RFC2822
+				| Fixed::RFC3339
+		),
+		ItemL10n::Chrono(Item::Numeric(spec, _)) => {
+			matches!(
+				spec,
+				Numeric::Year
+					| Numeric::YearDiv100
+					| Numeric::YearMod100
+					| Numeric::IsoYear
+					| Numeric::IsoYearDiv100
+					| Numeric::IsoYearMod100
+					| Numeric::Quarter
+					| Numeric::Month
+					| Numeric::Day | Numeric::WeekFromSun
+					| Numeric::WeekFromMon
+					| Numeric::IsoWeek
+					| Numeric::NumDaysFromSun
+					| Numeric::WeekdayFromMon
+					| Numeric::Ordinal
+					| Numeric::Hour | Numeric::Hour12
+					| Numeric::Minute
+					| Numeric::Second
+					| Numeric::Nanosecond
+					| Numeric::Timestamp
+			)
+		}
+		ItemL10n::Chrono(Item::Error) => false,
+		_ => true,
+	}
+}
+
+/// Returns the quarter of the year of a date, from 1 to 4, in its calendar. The thirteenth month
+/// of some calendars belongs to the fourth quarter.
+fn quarter(calendar: &dyn Calendar, date: &NaiveDate) -> u32 {
+	((calendar.fields(date).month - 1) / 3 + 1).min(4)
+}
+
+/// Writes a pattern such as `{1}, {0}`, with `arg` writing the placeholder of each index.
+pub fn write_placeholders(w: &mut fmt::Formatter, pattern: &str, mut arg: impl FnMut(&mut fmt::Formatter, usize) -> fmt::Result) -> fmt::Result {
+	let mut rest = pattern;
+	while let Some(start) = rest.find('{') {
+		w.write_str(&rest[..start])?;
+		let (index, tail) = rest[start + 1..].split_once('}').ok_or(fmt::Error)?;
+		arg(w, index.parse().map_err(|_| fmt::Error)?)?;
+		rest = tail;
+	}
+	w.write_str(rest)
+}
+
 /// Parses a [Numeric] value and returns its width and its formattable component.
 pub fn parse_numeric(
 	date: Option<&NaiveDate>,
 	time: Option<&NaiveTime>,
 	off: Option<&(String, FixedOffset)>,
 	spec: &Numeric,
+	calendar: &dyn Calendar,
 ) -> (usize, Option<i64>) {
 	use self::Numeric::*;
 
+	let ymd = |d: &NaiveDate| calendar.fields(d);
 	let week_from_sun = |d: &NaiveDate| (d.ordinal() as i32 - d.weekday().num_days_from_sunday() as i32 + 7) / 7;
 	let week_from_mon = |d: &NaiveDate| (d.ordinal() as i32 - d.weekday().num_days_from_monday() as i32 + 7) / 7;
 
 	match spec {
-		Year => (4, date.map(|d| i64::from(d.year()))),
-		YearDiv100 => (2, date.map(|d| div_floor(i64::from(d.year()), 100))),
-		YearMod100 => (2, date.map(|d| mod_floor(i64::from(d.year()), 100))),
+		Year => (calendar.year_width(), date.map(|d| i64::from(ymd(d).year))),
+		YearDiv100 => (2, date.map(|d| div_floor(i64::from(ymd(d).year), 100))),
+		YearMod100 => (2, date.map(|d| mod_floor(i64::from(ymd(d).year), 100))),
 		IsoYear => (4, date.map(|d| i64::from(d.iso_week().year()))),
 		IsoYearDiv100 => (2, date.map(|d| div_floor(i64::from(d.iso_week().year()), 100))),
 		IsoYearMod100 => (2, date.map(|d| mod_floor(i64::from(d.iso_week().year()), 100))),
-		Month => (2, date.map(|d| i64::from(d.month()))),
-		Day => (2, date.map(|d| i64::from(d.day()))),
+		Quarter => (1, date.map(|d| i64::from(quarter(calendar, d)))),
+		Month => (2, date.map(|d| i64::from(ymd(d).month))),
+		Day => (2, date.map(|d| i64::from(ymd(d).day))),
 		WeekFromSun => (2, date.map(|d| i64::from(week_from_sun(d)))),
 		WeekFromMon => (2, date.map(|d| i64::from(week_from_mon(d)))),
 		IsoWeek => (2, date.map(|d| i64::from(d.iso_week().week()))),
 		NumDaysFromSun => (1, date.map(|d| i64::from(d.weekday().num_days_from_sunday()))),
 		WeekdayFromMon => (1, date.map(|d| i64::from(d.weekday().number_from_monday()))),
-		Ordinal => (3, date.map(|d| i64::from(d.ordinal()))),
+		Ordinal => (3, date.map(|d| i64::from(ymd(d).ordinal))),
 		Hour => (2, time.map(|t| i64::from(t.hour()))),
 		Hour12 => (2, time.map(|t| i64::from(t.hour12().1))),
 		Minute => (2, time.map(|t| i64::from(t.minute()))),
@@ -144,29 +452,106 @@ pub fn parse_numeric(
 
 /// This function is nearly entirely copied from chrono's format()
 /// internal formats (3, 6 and 9-digits nanoseconds) have been disabled due to lack of access to chrono internals
-pub fn format_l10n<'a, I>(
+///
+/// The date and time are read through chrono's `Datelike` and `Timelike`, so that any type with
+/// their fields can be formatted, e.g. a wrapper of `NaiveDateTime`. Fields which aren't a valid date or
+/// time fail with `fmt::Error`.
+pub fn format_l10n<'a, D, T, I>(
+	w: &mut std::fmt::Formatter,
+	date: Option<&D>,
+	time: Option<&T>,
+	off: Option<&(String, FixedOffset)>,
+	items: I,
+	locale: &str,
+) -> std::fmt::Result
+where
+	D: Datelike,
+	T: Timelike,
+	I: Iterator,
+	I::Item: Into<ItemL10n<'a>>,
+{
+	let locale = Locale::parse(locale);
+	format_fields(w, date, time, off, items, &locale, &locale.calendar)
+}
+
+/// Formats like [format_l10n], but in the given calendar instead of the one selected by the locale.
+pub fn format_l10n_with_calendar<'a, D, T, I>(
+	w: &mut std::fmt::Formatter,
+	date: Option<&D>,
+	time: Option<&T>,
+	off: Option<&(String, FixedOffset)>,
+	items: I,
+	locale: &str,
+	calendar: &dyn Calendar,
+) -> std::fmt::Result
+where
+	D: Datelike,
+	T: Timelike,
+	I: Iterator,
+	I::Item: Into<ItemL10n<'a>>,
+{
+	format_fields(w, date, time, off, items, &Locale::parse(locale), calendar)
+}
+
+/// Reads the fields of the date and time into chrono's types, and formats them.
+fn format_fields<'a, D, T, I>(
+	w: &mut std::fmt::Formatter,
+	date: Option<&D>,
+	time: Option<&T>,
+	off: Option<&(String, FixedOffset)>,
+	items: I,
+	locale: &Locale,
+	calendar: &dyn Calendar,
+) -> std::fmt::Result
+where
+	D: Datelike,
+	T: Timelike,
+	I: Iterator,
+	I::Item: Into<ItemL10n<'a>>,
+{
+	let date = date.map(datelike::naive_date).map(|date| date.ok_or(fmt::Error)).transpose()?;
+	let time = time.map(datelike::naive_time).map(|time| time.ok_or(fmt::Error)).transpose()?;
+	format_items(w, date.as_ref(), time.as_ref(), off, None, items, locale, calendar, 0)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn format_items<'a, I>(
 	w: &mut std::fmt::Formatter,
 	date: Option<&NaiveDate>,
 	time: Option<&NaiveTime>,
 	off: Option<&(String, FixedOffset)>,
+	zone: Option<&Zone>,
 	items: I,
-	locale: &str,
+	locale: &Locale,
+	calendar: &dyn Calendar,
+	depth: usize,
 ) -> std::fmt::Result
 where
-	I: Iterator<Item = Item<'a>>,
+	I: Iterator,
+	I::Item: Into<ItemL10n<'a>>,
 {
-	let locale = locale.to_lowercase().replace('_', "-");
+	let items: Vec<ItemL10n<'a>> = items.map(Into::into).collect();
+	let with_day = items.iter().any(is_day_item);
 	for item in items {
 		match item {
-			Item::Literal(s) | Item::Space(s) => write!(w, "{}", s)?,
-			Item::OwnedLiteral(ref s) | Item::OwnedSpace(ref s) => write!(w, "{}", s)?,
+			ItemL10n::Chrono(Item::Literal(s) | Item::Space(s)) => write!(w, "{}", s)?,
+			ItemL10n::Chrono(Item::OwnedLiteral(ref s) | Item::OwnedSpace(ref s)) => write!(w, "{}", s)?,
 
-			Item::Numeric(spec, pad) => {
-				use self::Numeric::{IsoYear, Year};
-				let (width, v) = parse_numeric(date, time, off, &spec);
+			ItemL10n::Chrono(Item::Numeric(spec, pad)) => {
+				use self::Numeric::{Day, IsoYear, Year};
+				let (width, v) = parse_numeric(date, time, off, &spec, calendar);
 
 				if let Some(v) = v {
-					if (spec == Year || spec == IsoYear) && !(0..10_000).contains(&v) {
+					let mut w = NativeDigits::new(&mut *w, locale.numbering);
+					// e.g. days and years of the Hebrew calendar are written with letters in Hebrew
+					let name = match spec {
+						Day => date.and_then(|d| calendar.day_name(d, &locale.name)),
+						Year => calendar.year_name(v as i32, &locale.name),
+						_ => None,
+					};
+					if let Some(name) = name {
+						write!(w, "{}", name)?;
+					} else if (spec == Year || spec == IsoYear) && !(0..10_000).contains(&v) {
 						// non-four-digit years require an explicit sign as per ISO 8601
 						match pad {
 							Pad::None => write!(w, "{:+}", v)?,
@@ -185,8 +570,17 @@ where
 				}
 			}
 
-			Item::Fixed(spec) => parse_fixed(w, date, time, off, &spec, &locale).ok_or(std::fmt::Error)??,
-			Item::Error => return Err(std::fmt::Error),
+			ItemL10n::Chrono(Item::Fixed(spec)) => {
+				// interchange formats are always written with ASCII digits
+				let numbering = match spec {
+					Fixed::RFC2822 | Fixed::RFC3339 => NumberingSystem::Latn,
+					_ => locale.numbering,
+				};
+				let mut w = NativeDigits::new(&mut *w, numbering);
+				parse_fixed(&mut w, date, time, off, &spec, locale, calendar, with_day).ok_or(std::fmt::Error)??
+			}
+			ItemL10n::Chrono(Item::Error) => return Err(std::fmt::Error),
+			ItemL10n::Fixed(spec) => parse_fixed_l10n(w, date, time, off, zone, &spec, locale, calendar, depth)?,
 		}
 	}
 
@@ -0,0 +1,120 @@
+//! Formatters which parse a format string and resolve a locale once, to format many dates.
+
+use std::{error::Error, fmt, sync::Arc};
+
+use chrono::{FixedOffset, NaiveDate, NaiveTime};
+
+use crate::{
+	calendar::Calendar,
+	fmt::{format_items, is_supported},
+	items::{ItemL10n, StrftimeItemsL10n},
+	locale::Locale,
+	zone::{Zone, ZoneOffset},
+	DelayedFormatL10n, LocaleDate,
+};
+
+/// A format string compiled for a locale, which can be kept, e.g. in a `static`, and shared
+/// between threads to format any number of dates:
+///
+/// ```rust
+/// # use chrono::prelude::*;
+/// use chrono_lc::LocalizedFormatter;
+///
+/// let formatter = LocalizedFormatter::new("%A %-d %B %Y", "fr").unwrap();
+/// let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+/// assert_eq!(formatter.format(&dt).to_string(), "dimanche 8 juillet 2001");
+/// ```
+#[derive(Clone, Debug)]
+pub struct LocalizedFormatter {
+	items: Vec<ItemL10n<'static>>,
+	locale: Locale,
+	/// The calendar to format the dates in, instead of the one selected by the locale.
+	calendar: Option<Arc<dyn Calendar>>,
+}
+
+impl LocalizedFormatter {
+	/// Parses the format string, with the specifiers of `formatl`, for the locale. Fails if it has
+	/// an unknown specifier, or one which can't be formatted, e.g. `%3f` (use `%.3f`).
+	pub fn new(fmt: &str, locale: &str) -> Result<LocalizedFormatter, InvalidFormatError> {
+		let items: Vec<ItemL10n<'static>> = StrftimeItemsL10n::new(fmt).map(ItemL10n::to_owned).collect();
+		if !items.iter().all(is_supported) {
+			return Err(InvalidFormatError(()));
+		}
+		Ok(LocalizedFormatter {
+			items,
+			locale: Locale::parse(locale),
+			calendar: None,
+		})
+	}
+
+	/// Formats the dates in the given calendar, instead of the one selected by the locale.
+	pub fn with_calendar<C: Calendar + 'static>(self, calendar: C) -> LocalizedFormatter {
+		LocalizedFormatter {
+			calendar: Some(Arc::new(calendar)),
+			..self
+		}
+	}
+
+	/// Formats a date, returning a *temporary* object which can be used as an argument to
+	/// `format!` or others.
+	pub fn format<T: LocaleDate>(&self, value: &T) -> DelayedFormatterL10n<'_> {
+		let DelayedFormatL10n { date, time, off, zone, .. } = value.formatl("", "");
+		DelayedFormatterL10n {
+			formatter: self,
+			date,
+			time,
+			off,
+			zone,
+		}
+	}
+}
+
+/// A date being formatted by a [LocalizedFormatter], constructed via [LocalizedFormatter::format].
+#[derive(Debug)]
+pub struct DelayedFormatterL10n<'a> {
+	formatter: &'a LocalizedFormatter,
+	date: Option<NaiveDate>,
+	time: Option<NaiveTime>,
+	off: Option<(String, FixedOffset)>,
+	zone: Option<Zone>,
+}
+
+impl DelayedFormatterL10n<'_> {
+	/// Formats with the time zone `offset` is from, as [DelayedFormatL10n::with_zone] does.
+	pub fn with_zone<O: ZoneOffset>(self, offset: &O) -> Self {
+		DelayedFormatterL10n {
+			off: Some((offset.to_string(), offset.fix())),
+			zone: Zone::of(offset),
+			..self
+		}
+	}
+}
+
+impl fmt::Display for DelayedFormatterL10n<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let formatter = self.formatter;
+		format_items(
+			f,
+			self.date.as_ref(),
+			self.time.as_ref(),
+			self.off.as_ref(),
+			self.zone.as_ref(),
+			formatter.items.iter().cloned(),
+			&formatter.locale,
+			formatter.calendar.as_deref().unwrap_or(&formatter.locale.calendar),
+			0,
+		)
+	}
+}
+
+/// The error of a format string with an unknown specifier, returned by [LocalizedFormatter::new].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidFormatError(());
+
+impl fmt::Display for InvalidFormatError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("invalid format string")
+	}
+}
+
+impl Error for InvalidFormatError {}
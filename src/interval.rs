@@ -0,0 +1,311 @@
+//! Intervals between two dates or times, written with the fields they share only once, e.g.
+//! "8–10 July 2001" rather than "8 July 2001 – 10 July 2001".
+
+use std::{fmt, sync::Arc};
+
+use crate::{
+	calendar::Calendar,
+	fmt::{format_items, write_placeholders},
+	items::{FixedL10n, ItemL10n, StrftimeItemsL10n},
+	locale::Locale,
+	util,
+	zone::{self, Zone},
+};
+use chrono::{
+	format::{Fixed, Item, Numeric},
+	FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike,
+};
+
+/// The parts of its ends an interval is written with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntervalStyle {
+	/// The dates, e.g. "8–10 July 2001" (`date_interval_fmt` in the locale data).
+	Date,
+	/// The times in hours and minutes, e.g. "12:34 – 1:30 AM" (`time_interval_fmt` in the locale
+	/// data). Ends on different days are written with their dates, as [IntervalStyle::DateTime]
+	/// does.
+	Time,
+	/// The dates and times, e.g. "July 8, 2001, 12:34 – 1:30 AM", joined with
+	/// `date_time_interval_fmt` in the locale data. Ends on different days are written in full, with
+	/// `interval_fallback_fmt`.
+	DateTime,
+}
+
+pub trait LocaleInterval {
+	/// Formats the interval from `self` to `end`, writing the fields they share once. The greatest
+	/// field they differ in is found in the calendar of the locale and selects the locale's pattern.
+	/// An `end` before `self` starts the interval, so that it's still written from its earlier end.
+	fn formatl_interval(&self, end: &Self, style: IntervalStyle, locale: &str) -> DelayedIntervalL10n;
+}
+
+impl LocaleInterval for chrono::NaiveDate {
+	fn formatl_interval(&self, end: &Self, style: IntervalStyle, locale: &str) -> DelayedIntervalL10n {
+		let endpoint = |date: &NaiveDate| Endpoint {
+			date: Some(*date),
+			time: None,
+			off: None,
+			zone: None,
+		};
+		let (start, end) = ordered(self, end);
+		DelayedIntervalL10n::new(endpoint(start), endpoint(end), style, locale)
+	}
+}
+
+impl LocaleInterval for chrono::NaiveDateTime {
+	fn formatl_interval(&self, end: &Self, style: IntervalStyle, locale: &str) -> DelayedIntervalL10n {
+		let endpoint = |dt: &chrono::NaiveDateTime| Endpoint {
+			date: Some(dt.date()),
+			time: Some(dt.time()),
+			off: None,
+			zone: None,
+		};
+		let (start, end) = ordered(self, end);
+		DelayedIntervalL10n::new(endpoint(start), endpoint(end), style, locale)
+	}
+}
+
+/// Formats the ends in their time zones, as [LocaleDate::formatl](crate::LocaleDate::formatl) does.
+impl<Tz: TimeZone> LocaleInterval for chrono::DateTime<Tz>
+where
+	Tz::Offset: 'static,
+{
+	fn formatl_interval(&self, end: &Self, style: IntervalStyle, locale: &str) -> DelayedIntervalL10n {
+		let endpoint = |dt: &chrono::DateTime<Tz>| {
+			let local = dt.naive_local();
+			let (off, zone) = zone::describe_any(dt.offset());
+			Endpoint {
+				date: Some(local.date()),
+				time: Some(local.time()),
+				off: Some(off),
+				zone,
+			}
+		};
+		let (start, end) = ordered(self, end);
+		DelayedIntervalL10n::new(endpoint(start), endpoint(end), style, locale)
+	}
+}
+
+/// Returns the ends of an interval, the earlier one first.
+fn ordered<'a, T: PartialOrd>(start: &'a T, end: &'a T) -> (&'a T, &'a T) {
+	if end < start {
+		(end, start)
+	} else {
+		(start, end)
+	}
+}
+
+/// One end of an interval.
+#[derive(Clone, Debug)]
+struct Endpoint {
+	date: Option<NaiveDate>,
+	time: Option<NaiveTime>,
+	off: Option<(String, FixedOffset)>,
+	zone: Option<Zone>,
+}
+
+/// A *temporary* object which can be used as an argument to `format!` or others, constructed
+/// via [LocaleInterval::formatl_interval].
+#[derive(Debug)]
+pub struct DelayedIntervalL10n {
+	/// The locale to format the interval in
+	locale: String,
+	/// The parts of the ends to write.
+	style: IntervalStyle,
+	start: Endpoint,
+	end: Endpoint,
+	/// The calendar to format the dates in, instead of the one selected by the locale.
+	calendar: Option<Arc<dyn Calendar>>,
+	/// The pattern to write the interval with, instead of the locale's.
+	pattern: Option<String>,
+}
+
+impl DelayedIntervalL10n {
+	fn new(start: Endpoint, end: Endpoint, style: IntervalStyle, locale: &str) -> DelayedIntervalL10n {
+		DelayedIntervalL10n {
+			locale: locale.to_owned(),
+			style,
+			start,
+			end,
+			calendar: None,
+			pattern: None,
+		}
+	}
+
+	/// Formats the dates in the given calendar, instead of the one selected by the locale.
+	pub fn with_calendar<C: Calendar + 'static>(self, calendar: C) -> DelayedIntervalL10n {
+		DelayedIntervalL10n {
+			calendar: Some(Arc::new(calendar)),
+			..self
+		}
+	}
+
+	/// Writes the interval with a strftime pattern instead of the locale's, e.g.
+	/// `%H:%M–%H:%M %Z`. The first field written a second time and those following it are the
+	/// end's, the others the start's.
+	pub fn with_pattern(self, pattern: &str) -> DelayedIntervalL10n {
+		DelayedIntervalL10n {
+			pattern: Some(pattern.to_owned()),
+			..self
+		}
+	}
+}
+
+impl fmt::Display for DelayedIntervalL10n {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let locale = Locale::parse(&self.locale);
+		let interval = Interval {
+			locale: &locale,
+			calendar: self.calendar.as_deref().unwrap_or(&locale.calendar),
+			start: &self.start,
+			end: &self.end,
+		};
+		if let Some(pattern) = &self.pattern {
+			return interval.write_pattern(f, pattern);
+		}
+		match self.style {
+			IntervalStyle::Date => interval.write_dates(f),
+			IntervalStyle::Time => interval.write_times(f),
+			IntervalStyle::DateTime => interval.write_date_times(f),
+		}
+	}
+}
+
+/// An interval being formatted.
+struct Interval<'a> {
+	locale: &'a Locale,
+	calendar: &'a dyn Calendar,
+	start: &'a Endpoint,
+	end: &'a Endpoint,
+}
+
+impl Interval<'_> {
+	fn write_dates(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let (start, end) = (self.start.date.ok_or(fmt::Error)?, self.end.date.ok_or(fmt::Error)?);
+		let (start, end) = (self.calendar.fields(&start), self.calendar.fields(&end));
+		let difference = if start.year != end.year {
+			1
+		} else if start.month != end.month || start.leap_month != end.leap_month {
+			2
+		} else if start.day != end.day {
+			3
+		} else {
+			0
+		};
+		self.write_pattern(f, util::date_interval_fmt(difference, &self.locale.name))
+	}
+
+	fn write_times(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let (start, end) = (self.start.time.ok_or(fmt::Error)?, self.end.time.ok_or(fmt::Error)?);
+		// times on different days are written with their dates, rather than as if on the same day
+		if self.start.date != self.end.date {
+			return self.write_date_times(f);
+		}
+		let difference = if start.hour() / 12 != end.hour() / 12 {
+			1
+		} else if start.hour() != end.hour() {
+			2
+		} else if start.minute() != end.minute() {
+			3
+		} else {
+			0
+		};
+		self.write_pattern(f, util::time_interval_fmt(difference, &self.locale.name))
+	}
+
+	fn write_date_times(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let name = self.locale.name.as_str();
+		if self.start.date == self.end.date {
+			return write_placeholders(f, util::date_time_interval_fmt(name), |f, index| match index {
+				0 => self.write_times(f),
+				_ => self.write_items(f, self.start, StrftimeItemsL10n::new(util::date_interval_fmt(0, name))),
+			});
+		}
+
+		write_placeholders(f, util::interval_fallback_fmt(name), |f, index| {
+			let endpoint = if index == 0 { self.start } else { self.end };
+			write_placeholders(f, util::date_time_interval_fmt(name), |f, index| match index {
+				0 => self.write_items(f, endpoint, StrftimeItemsL10n::new(util::time_interval_fmt(0, name))),
+				_ => self.write_items(f, endpoint, StrftimeItemsL10n::new(util::date_interval_fmt(0, name))),
+			})
+		})
+	}
+
+	/// Writes an interval pattern, where the first field written a second time (e.g. the second
+	/// `%-d` of `%-d–%-d %B %Y`) and all those following it belong to the end of the interval.
+	fn write_pattern(&self, f: &mut fmt::Formatter, pattern: &str) -> fmt::Result {
+		let items: Vec<ItemL10n> = StrftimeItemsL10n::new(pattern).collect();
+		let mut fields = Vec::new();
+		let split = items
+			.iter()
+			.position(|item| match field(item) {
+				Some(field) if fields.contains(&field) => true,
+				Some(field) => {
+					fields.push(field);
+					false
+				}
+				None => false,
+			})
+			.unwrap_or(items.len());
+
+		self.write_items(f, self.start, items[..split].iter().cloned())?;
+		self.write_items(f, self.end, items[split..].iter().cloned())
+	}
+
+	fn write_items<'a, I>(&self, f: &mut fmt::Formatter, endpoint: &Endpoint, items: I) -> fmt::Result
+	where
+		I: Iterator,
+		I::Item: Into<ItemL10n<'a>>,
+	{
+		let (date, time, off, zone) = (
+			endpoint.date.as_ref(),
+			endpoint.time.as_ref(),
+			endpoint.off.as_ref(),
+			endpoint.zone.as_ref(),
+		);
+		format_items(f, date, time, off, zone, items, self.locale, self.calendar, 0)
+	}
+}
+
+/// The fields of a date and time which an interval pattern writes for both of its ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Field {
+	Era,
+	Year,
+	Month,
+	Day,
+	AmPm,
+	Hour,
+	Minute,
+	Second,
+}
+
+fn field(item: &ItemL10n) -> Option<Field> {
+	let field = match item {
+		ItemL10n::Chrono(Item::Numeric(spec, _)) => match spec {
+			Numeric::Year | Numeric::YearDiv100 | Numeric::YearMod100 => Field::Year,
+			Numeric::IsoYear | Numeric::IsoYearDiv100 | Numeric::IsoYearMod100 => Field::Year,
+			Numeric::Month => Field::Month,
+			Numeric::Day | Numeric::Ordinal | Numeric::NumDaysFromSun | Numeric::WeekdayFromMon => Field::Day,
+			Numeric::WeekFromSun | Numeric::WeekFromMon | Numeric::IsoWeek => Field::Day,
+			Numeric::Hour | Numeric::Hour12 => Field::Hour,
+			Numeric::Minute => Field::Minute,
+			Numeric::Second => Field::Second,
+			_ => return None,
+		},
+		ItemL10n::Chrono(Item::Fixed(spec)) => match spec {
+			Fixed::ShortMonthName | Fixed::LongMonthName => Field::Month,
+			Fixed::ShortWeekdayName | Fixed::LongWeekdayName => Field::Day,
+			Fixed::LowerAmPm | Fixed::UpperAmPm => Field::AmPm,
+			_ => return None,
+		},
+		ItemL10n::Fixed(spec) => match spec {
+			FixedL10n::EraName | FixedL10n::NarrowEraName => Field::Era,
+			FixedL10n::YearOfEra | FixedL10n::PaddedYearOfEra(_) | FixedL10n::YearWithEra => Field::Year,
+			FixedL10n::NarrowMonthName => Field::Month,
+			FixedL10n::OrdinalDay | FixedL10n::FeminineOrdinalDay | FixedL10n::PaddedOrdinal(_) => Field::Day,
+			_ => return None,
+		},
+		_ => return None,
+	};
+	Some(field)
+}
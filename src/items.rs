@@ -0,0 +1,309 @@
+use chrono::format::{Item, Numeric, Pad, StrftimeItems};
+
+use crate::style::{DateStyle, TimeStyle};
+
+/// A formatting item, either one of chrono's or a locale-dependent one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ItemL10n<'a> {
+	/// An item formatted the same way chrono does, translating names.
+	Chrono(Item<'a>),
+	/// A fixed-format item whose representation is defined by the locale.
+	Fixed(FixedL10n),
+}
+
+impl ItemL10n<'_> {
+	/// Converts items that borrow from the format string into owned ones.
+	pub fn to_owned(self) -> ItemL10n<'static> {
+		match self {
+			ItemL10n::Chrono(item) => ItemL10n::Chrono(item.to_owned()),
+			ItemL10n::Fixed(spec) => ItemL10n::Fixed(spec),
+		}
+	}
+}
+
+impl<'a> From<Item<'a>> for ItemL10n<'a> {
+	fn from(item: Item<'a>) -> Self {
+		ItemL10n::Chrono(item)
+	}
+}
+
+/// Fixed-format items whose representation is defined by the locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FixedL10n {
+	/// The locale's date and time representation (`%c`, `d_t_fmt` in the locale data).
+	DateTime,
+	/// The locale's date representation (`%x`, `d_fmt` in the locale data).
+	Date,
+	/// The locale's time representation (`%X`, `t_fmt` in the locale data).
+	Time,
+	/// The locale's 12-hour clock time representation (`%r`, `t_fmt_ampm` in the locale data).
+	Time12,
+	/// The name of the era in the calendar of the locale, e.g. "AD" or "平成" (`%EC`).
+	EraName,
+	/// The narrow name of the era, e.g. "A" or "H" (`%EN`).
+	NarrowEraName,
+	/// The year of the era, without padding (`%Ey`).
+	YearOfEra,
+	/// The locale's representation of the year with its era, e.g. "平成13年" (`%EY`,
+	/// `era_year_fmt` in the locale data, or `first_era_year_fmt` for the first year of a Japanese
+	/// imperial era or of Minguo).
+	YearWithEra,
+	/// The day of the month as an ordinal number, e.g. "8th" or "1er" (`%Eo`, `ordinal_day_fmt`
+	/// in the locale data, by the plural category of the day in `ordinal_rules`).
+	OrdinalDay,
+	/// The day of the month as a feminine ordinal number, e.g. "1ª" or "1re" (`%Ef`,
+	/// `ordinal_day_fmt_feminine` in the locale data), or as `%Eo` in languages without genders.
+	FeminineOrdinalDay,
+	/// The animal of the Chinese zodiac for the year, e.g. "Snake" or "蛇" (`%Ez`).
+	Zodiac,
+	/// The name of the time zone at the date, e.g. "Pacific Daylight Time" (`%EZ`), or the offset
+	/// in the localized GMT format (as `%EG`) for zones without one.
+	///
+	/// Zones are named after their current CLDR metazone, whatever the date: the dates before a
+	/// zone changed metazones (e.g. `America/Indiana/Knox` before 2006) get the name of the current
+	/// one.
+	ZoneName,
+	/// The name of the time zone at any date, e.g. "Pacific Time" (`%Ev`), or the offset in the
+	/// localized GMT format (as `%EG`) for zones without one. Like [FixedL10n::ZoneName], it's the
+	/// name of the current metazone of the zone.
+	GenericZoneName,
+	/// The city of the time zone, e.g. "Los Angeles" (`%EV`), or the offset in the localized GMT
+	/// format (as `%EG`) for unknown zones.
+	ZoneCity,
+	/// The offset from UTC in the GMT format of the locale, e.g. "GMT+9:30", "UTC−3" or "GMT"
+	/// (`%EO`, `gmt_fmt` and `gmt_offset_fmt` in the locale data, or `gmt_zero_fmt` for UTC).
+	GmtOffset,
+	/// The offset from UTC in the long GMT format of the locale, with padded hours and minutes,
+	/// e.g. "GMT+09:30" (`%EG`).
+	LongGmtOffset,
+	/// The locale's date pattern of a style (`date_formats` in the locale data). It has no
+	/// specifier, and is formatted by [LocaleDate::formatl_date_style](crate::LocaleDate::formatl_date_style).
+	StyledDate(DateStyle),
+	/// The locale's time pattern of a style (`time_formats` in the locale data).
+	StyledTime(TimeStyle),
+	/// The locale's date and time patterns of the styles, joined with the pattern of the date
+	/// style (`date_time_formats` in the locale data).
+	StyledDateTime(DateStyle, TimeStyle),
+	/// The abbreviated name of the quarter of the year, e.g. "Q3" (`QQQ` in ICU patterns,
+	/// `short_quarters` in the locale data).
+	ShortQuarterName,
+	/// The name of the quarter of the year, e.g. "3rd quarter" (`QQQQ` in ICU patterns,
+	/// `long_quarters` in the locale data).
+	LongQuarterName,
+	/// The hour on a clock from 0 to 11, zero-padded if `padded` (`K` and `KK` in ICU patterns).
+	Hour0To11 { padded: bool },
+	/// The hour on a clock from 1 to 24, zero-padded if `padded` (`k` and `kk` in ICU patterns).
+	Hour1To24 { padded: bool },
+	/// The first digits of the fraction of the second, without a dot (`S` to `SSSSSSSSS` in ICU
+	/// patterns).
+	SecondFraction(usize),
+	/// The narrow name of the month, e.g. "J" (`MMMMM` and `LLLLL` in ICU patterns,
+	/// `narrow_months` in the locale data).
+	NarrowMonthName,
+	/// The quarter of the year, zero-padded to 2 digits (`QQ` in ICU patterns).
+	PaddedQuarter,
+	/// The day of the year, zero-padded to a number of digits (`DD` and `DDD` in ICU patterns).
+	PaddedOrdinal(usize),
+	/// The year of the era, zero-padded to a number of digits (`yyy` and `yyyy` in ICU patterns).
+	PaddedYearOfEra(usize),
+}
+
+/// Parsing iterator for strftime-like format strings, which yields the locale-dependent
+/// specifiers as [ItemL10n::Fixed] and leaves everything else to chrono's [StrftimeItems].
+#[derive(Clone, Debug)]
+pub struct StrftimeItemsL10n<'a> {
+	/// Remaining portion of the string, not yet handed to chrono.
+	remainder: &'a str,
+	/// Items of the chunk of the string which doesn't contain any locale-dependent specifier.
+	chrono: StrftimeItems<'a>,
+}
+
+impl<'a> StrftimeItemsL10n<'a> {
+	/// Creates a new parsing iterator from a strftime-like format string.
+	pub fn new(s: &'a str) -> StrftimeItemsL10n<'a> {
+		StrftimeItemsL10n {
+			remainder: s,
+			chrono: StrftimeItems::new(""),
+		}
+	}
+}
+
+impl<'a> Iterator for StrftimeItemsL10n<'a> {
+	type Item = ItemL10n<'a>;
+
+	fn next(&mut self) -> Option<ItemL10n<'a>> {
+		loop {
+			if let Some(item) = self.chrono.next() {
+				return Some(ItemL10n::Chrono(item));
+			}
+			if self.remainder.is_empty() {
+				return None;
+			}
+
+			match find_fixed(self.remainder) {
+				Some((0, len, spec)) => {
+					self.remainder = &self.remainder[len..];
+					return Some(ItemL10n::Fixed(spec));
+				}
+				Some((start, _, _)) => {
+					self.chrono = StrftimeItems::new(&self.remainder[..start]);
+					self.remainder = &self.remainder[start..];
+				}
+				None => {
+					self.chrono = StrftimeItems::new(self.remainder);
+					self.remainder = "";
+				}
+			}
+		}
+	}
+}
+
+/// Finds the first locale-dependent specifier, returning its position, its length and its item.
+fn find_fixed(s: &str) -> Option<(usize, usize, FixedL10n)> {
+	let mut specs = s.char_indices().filter(|&(_, c)| c == '%').map(|(i, _)| i);
+
+	while let Some(i) = specs.next() {
+		let mut chars = s[i + 1..].chars();
+		let (len, spec) = match (chars.next(), chars.next()) {
+			(Some('c'), _) => (2, FixedL10n::DateTime),
+			(Some('x'), _) => (2, FixedL10n::Date),
+			(Some('X'), _) => (2, FixedL10n::Time),
+			(Some('r'), _) => (2, FixedL10n::Time12),
+			(Some('E'), Some('C')) => (3, FixedL10n::EraName),
+			(Some('E'), Some('N')) => (3, FixedL10n::NarrowEraName),
+			(Some('E'), Some('y')) => (3, FixedL10n::YearOfEra),
+			(Some('E'), Some('Y')) => (3, FixedL10n::YearWithEra),
+			(Some('E'), Some('o')) => (3, FixedL10n::OrdinalDay),
+			(Some('E'), Some('f')) => (3, FixedL10n::FeminineOrdinalDay),
+			(Some('E'), Some('z')) => (3, FixedL10n::Zodiac),
+			(Some('E'), Some('Z')) => (3, FixedL10n::ZoneName),
+			(Some('E'), Some('v')) => (3, FixedL10n::GenericZoneName),
+			(Some('E'), Some('V')) => (3, FixedL10n::ZoneCity),
+			(Some('E'), Some('O')) => (3, FixedL10n::GmtOffset),
+			(Some('E'), Some('G')) => (3, FixedL10n::LongGmtOffset),
+			(Some('%'), _) => {
+				// escaped `%`, skip the second one as well
+				specs.next();
+				continue;
+			}
+			_ => continue,
+		};
+		return Some((i, len, spec));
+	}
+
+	None
+}
+
+/// Parsing iterator for ICU (CLDR) date patterns, e.g. `EEEE d MMMM y`, where each letter is a field
+/// whose width is told by how many times it's repeated, and text is quoted (`'at'`, `''` for a
+/// quote).
+///
+/// The fields are `G`, `y`, `Y`, `Q`/`q`, `M`/`L`, `w` (the ISO week), `d`, `D`, `E`, `e`/`c`
+/// (the weekday, numbered from Monday), `a`, `h`, `H`, `K`, `k`, `m`, `s`, `S`, `z`, `Z`, `O`, `v`,
+/// `V`, `X` and `x`. Other letters are errors.
+#[derive(Clone, Debug)]
+pub struct IcuItemsL10n<'a> {
+	/// Remaining portion of the pattern.
+	remainder: &'a str,
+	/// Whether the remainder is inside a quoted literal.
+	quoted: bool,
+}
+
+impl<'a> IcuItemsL10n<'a> {
+	/// Creates a new parsing iterator from an ICU pattern.
+	pub fn new(s: &'a str) -> IcuItemsL10n<'a> {
+		IcuItemsL10n { remainder: s, quoted: false }
+	}
+}
+
+impl<'a> Iterator for IcuItemsL10n<'a> {
+	type Item = ItemL10n<'a>;
+
+	fn next(&mut self) -> Option<ItemL10n<'a>> {
+		loop {
+			let Some(c) = self.remainder.chars().next() else {
+				// a quote left open
+				return self.quoted.then(|| {
+					self.quoted = false;
+					Item::Error.into()
+				});
+			};
+			if c == '\'' {
+				if let Some(rest) = self.remainder.strip_prefix("''") {
+					self.remainder = rest;
+					return Some(Item::Literal("'").into());
+				}
+				self.remainder = &self.remainder[1..];
+				self.quoted = !self.quoted;
+				continue;
+			}
+
+			if !self.quoted && c.is_ascii_alphabetic() {
+				let count = self.remainder.chars().take_while(|&d| d == c).count();
+				self.remainder = &self.remainder[count..];
+				return Some(icu_field(c, count));
+			}
+
+			// text, up to the next field or quote
+			let end = self.remainder[c.len_utf8()..]
+				.find(|d: char| d == '\'' || (!self.quoted && d.is_ascii_alphabetic()))
+				.map_or(self.remainder.len(), |end| end + c.len_utf8());
+			let (text, rest) = self.remainder.split_at(end);
+			self.remainder = rest;
+			return Some(Item::Literal(text).into());
+		}
+	}
+}
+
+/// Returns the item of a field of an ICU pattern.
+fn icu_field(symbol: char, count: usize) -> ItemL10n<'static> {
+	use chrono::format::{Fixed::*, Numeric::*};
+
+	let pad = if count >= 2 { Pad::Zero } else { Pad::None };
+	let fixed = |spec: FixedL10n| ItemL10n::Fixed(spec);
+	match (symbol, count) {
+		('G', 1..=4) => fixed(FixedL10n::EraName),
+		('G', 5) => fixed(FixedL10n::NarrowEraName),
+		('y', 2) => Item::Numeric(YearMod100, Pad::Zero).into(),
+		// the year of the era, as in the calendar's eras
+		('y', 1) => fixed(FixedL10n::YearOfEra),
+		('y', _) => fixed(FixedL10n::PaddedYearOfEra(count)),
+		('Y', 2) => Item::Numeric(IsoYearMod100, Pad::Zero).into(),
+		('Y', _) => Item::Numeric(IsoYear, if count >= 4 { Pad::Zero } else { Pad::None }).into(),
+		// quarters have a single digit
+		('Q' | 'q', 1 | 5) => Item::Numeric(Quarter, Pad::None).into(),
+		('Q' | 'q', 2) => fixed(FixedL10n::PaddedQuarter),
+		('Q' | 'q', 3) => fixed(FixedL10n::ShortQuarterName),
+		('Q' | 'q', 4) => fixed(FixedL10n::LongQuarterName),
+		('M' | 'L', 1 | 2) => Item::Numeric(Month, pad).into(),
+		('M' | 'L', 3) => Item::Fixed(ShortMonthName).into(),
+		('M' | 'L', 4) => Item::Fixed(LongMonthName).into(),
+		('M' | 'L', 5) => fixed(FixedL10n::NarrowMonthName),
+		('w', 1 | 2) => Item::Numeric(IsoWeek, pad).into(),
+		('d', 1 | 2) => Item::Numeric(Day, pad).into(),
+		('D', 1) => Item::Numeric(Ordinal, Pad::None).into(),
+		('D', 2 | 3) => fixed(FixedL10n::PaddedOrdinal(count)),
+		('e' | 'c', 1 | 2) => Item::Numeric(WeekdayFromMon, Pad::None).into(),
+		('E', 1..=3) | ('E' | 'e' | 'c', 3 | 5 | 6) => Item::Fixed(ShortWeekdayName).into(),
+		('E' | 'e' | 'c', 4) => Item::Fixed(LongWeekdayName).into(),
+		('a', 1..=5) => Item::Fixed(UpperAmPm).into(),
+		('h', 1 | 2) => Item::Numeric(Hour12, pad).into(),
+		('H', 1 | 2) => Item::Numeric(Numeric::Hour, pad).into(),
+		('K', 1 | 2) => fixed(FixedL10n::Hour0To11 { padded: count == 2 }),
+		('k', 1 | 2) => fixed(FixedL10n::Hour1To24 { padded: count == 2 }),
+		('m', 1 | 2) => Item::Numeric(Minute, pad).into(),
+		('s', 1 | 2) => Item::Numeric(Second, pad).into(),
+		('S', 1..=9) => fixed(FixedL10n::SecondFraction(count)),
+		('z', 1..=3) | ('v', 1) => Item::Fixed(TimezoneName).into(),
+		('z', 4) => fixed(FixedL10n::ZoneName),
+		('v', 4) => fixed(FixedL10n::GenericZoneName),
+		('V', 3) => fixed(FixedL10n::ZoneCity),
+		('O', 1) => fixed(FixedL10n::GmtOffset),
+		('O', 4) | ('Z', 4) => fixed(FixedL10n::LongGmtOffset),
+		('Z', 1..=3) | ('x', 1 | 2) => Item::Fixed(TimezoneOffset).into(),
+		('Z', 5) | ('X', 3) => Item::Fixed(TimezoneOffsetColonZ).into(),
+		('X', 1 | 2) => Item::Fixed(TimezoneOffsetZ).into(),
+		('x', 3) => Item::Fixed(TimezoneOffsetColon).into(),
+		_ => Item::Error.into(),
+	}
+}
@@ -0,0 +1,105 @@
+//! Formatting of the dates and times of the `jiff` crate, with the `jiff` feature. Their fields
+//! are converted to chrono's types, and the time zones of `Zoned` values keep the abbreviation and
+//! the identifier jiff finds in its time zone database.
+
+use std::fmt;
+
+use chrono::{FixedOffset, NaiveDate, NaiveTime, Offset};
+use jiff::{civil, tz::TimeZone, Timestamp, Zoned};
+
+use crate::{items::StrftimeItemsL10n, DelayedFormatL10n, LocaleDate, ZoneOffset};
+
+fn naive_date(date: civil::Date) -> Option<NaiveDate> {
+	NaiveDate::from_ymd_opt(date.year().into(), date.month() as u32, date.day() as u32)
+}
+
+fn naive_time(time: civil::Time) -> Option<NaiveTime> {
+	NaiveTime::from_hms_nano_opt(
+		time.hour() as u32,
+		time.minute() as u32,
+		time.second() as u32,
+		time.subsec_nanosecond() as u32,
+	)
+}
+
+/// The offset of a `Zoned` value, with the abbreviation and the identifier of its time zone.
+#[derive(Clone, Debug)]
+struct JiffOffset {
+	fix: FixedOffset,
+	abbreviation: String,
+	zone_id: Option<String>,
+	dst: bool,
+}
+
+/// The greatest offset chrono can represent, a second short of a day. jiff's go up to 25:59:59.
+const MAX_OFFSET: i32 = 86_399;
+
+impl JiffOffset {
+	/// Returns the offset of a `Zoned` value, clamped to the offsets chrono can represent.
+	fn of(zoned: &Zoned) -> JiffOffset {
+		let info = zoned.time_zone().to_offset_info(zoned.timestamp());
+		let seconds = info.offset().seconds().clamp(-MAX_OFFSET, MAX_OFFSET);
+		JiffOffset {
+			fix: FixedOffset::east_opt(seconds).expect("offset within a day"),
+			abbreviation: info.abbreviation().to_owned(),
+			zone_id: zoned.time_zone().iana_name().map(str::to_owned),
+			dst: info.dst().is_dst(),
+		}
+	}
+}
+
+impl Offset for JiffOffset {
+	fn fix(&self) -> FixedOffset {
+		self.fix
+	}
+}
+
+/// The abbreviation of the time zone, e.g. "CEST", written by `%Z`.
+impl fmt::Display for JiffOffset {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.abbreviation)
+	}
+}
+
+impl ZoneOffset for JiffOffset {
+	fn zone_id(&self) -> Option<&str> {
+		self.zone_id.as_deref()
+	}
+
+	fn is_dst(&self) -> bool {
+		self.dst
+	}
+}
+
+impl LocaleDate for civil::Date {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(naive_date(*self), None, StrftimeItemsL10n::new(fmt), locale)
+	}
+}
+
+impl LocaleDate for civil::Time {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(None, naive_time(*self), StrftimeItemsL10n::new(fmt), locale)
+	}
+}
+
+impl LocaleDate for civil::DateTime {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(naive_date(self.date()), naive_time(self.time()), StrftimeItemsL10n::new(fmt), locale)
+	}
+}
+
+/// Offsets of a day or more, which jiff allows but chrono doesn't, are written as the greatest
+/// offset chrono has, e.g. "+2359" for `%z`.
+impl LocaleDate for Zoned {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		self.datetime().formatl(fmt, locale).with_zone(&JiffOffset::of(self))
+	}
+}
+
+/// Formats in UTC, as `SystemTime` is.
+impl LocaleDate for Timestamp {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		self.to_zoned(TimeZone::UTC).formatl(fmt, locale)
+	}
+}
@@ -8,7 +8,7 @@
 //! ```toml
 //! [dependencies]
 //! chrono = "0.4"
-//! chrono_lc = "0.1.7"
+//! chrono_lc = "0.2.0"
 //! ```
 //!
 //! Then put this in your `lib.rs` or `main.rs`:
@@ -40,6 +40,60 @@
 //! All of [chrono's formatting placeholders](https://docs.rs/chrono/0.4.6/chrono/format/strftime/index.html)
 //! work except for `%3f`, `%6f` and `%9f` (but `%.3f`, `%.6f` and `%.9f` work normally)
 //!
+//! To format many dates, e.g. the rows of a table, a [LocalizedFormatter] parses the format string
+//! and resolves the locale only once, and fails on unknown specifiers:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! use chrono_lc::LocalizedFormatter;
+//!
+//! let formatter = LocalizedFormatter::new("%d/%m/%Y %A", "it").unwrap();
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! assert_eq!(formatter.format(&dt).to_string(), "08/07/2001 domenica");
+//! assert!(LocalizedFormatter::new("%Q", "it").is_err());
+//! ```
+//!
+//! The value returned by `formatl` borrows the format string. [LocaleDate::formatl_owned] returns
+//! an [OwnedDelayedFormatL10n] instead, which can outlive it:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! use chrono_lc::{LocaleDate, OwnedDelayedFormatL10n};
+//!
+//! fn month_and_year(date: &NaiveDate, separator: &str, locale: &str) -> OwnedDelayedFormatL10n {
+//!     date.formatl_owned(&format!("%B{}%Y", separator), locale)
+//! }
+//!
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! assert_eq!(month_and_year(&dt, " ", "nl").to_string(), "juli 2001");
+//! ```
+//!
+//! Times of day (`NaiveTime`) and `SystemTime`, in UTC, are formatted the same way. The names of
+//! chrono's `Weekday` and `Month` are given by [LocaleName::name_l]:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! use chrono_lc::{LocaleDate, LocaleName, NameWidth};
+//!
+//! let time = NaiveTime::from_hms_opt(13, 4, 5).unwrap();
+//! assert_eq!(time.formatl("%-I:%M %p", "en").to_string(), "1:04 PM");
+//! assert_eq!(Weekday::Sun.name_l("fr", NameWidth::Long), "dimanche");
+//! assert_eq!(Month::January.name_l("de", NameWidth::Short), "Jan");
+//! ```
+//!
+//! Other types with the fields of a date and time, e.g. wrappers of chrono's types, are read
+//! through chrono's `Datelike` and `Timelike` traits and formatted by [formatl_datelike], with
+//! their offset if they have one. It returns `None` for fields that aren't a valid date and time:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::formatl_datelike;
+//! #
+//! # let value = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap();
+//! let format = formatl_datelike(&value, "%A %H:%M %Z", "fr", Some(&Utc)).expect("invalid date");
+//! assert_eq!(format.to_string(), "dimanche 00:34 UTC");
+//! ```
+//!
 //! ## Locale format
 //!
 //! The `formatl` method supports locales in different formats, based on ISO-639-1 and ISO-3166.
@@ -54,45 +108,436 @@
 //! For example: by requesting `it_IT` it will first try in `it-it`, then in `it` and, if it still
 //! doesn't find it, it will use the default: `C` (english)
 //!
+//! To use the locale the user has set in the environment (`LC_ALL`, `LC_TIME`, `LANG` or
+//! `LANGUAGE`), use `formatl_env` or pass the result of `locale_from_env` to `formatl`:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::{locale_from_env, LocaleDate};
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! println!("{}", dt.formatl_env("%A %e %B %Y"));
+//! println!("{}", dt.formatl("%A %e %B %Y", &locale_from_env()));
+//! ```
+//!
+//! Web services can instead pick the locale from the `Accept-Language` header of the request:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::{locale_from_accept_language, LocaleDate};
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! let locale = locale_from_accept_language("fr-CH, fr;q=0.9, en;q=0.8");
+//! assert_eq!(dt.formatl("%A %e %B %Y", &locale).to_string(), "dimanche  8 juillet 2001");
+//! ```
+//!
+//! Instead of a format string, the patterns the locale defines for the short, medium, long and
+//! full styles of dates and times can be used with [LocaleDate::formatl_style] and its
+//! date-only and time-only siblings:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! use chrono_lc::{DateStyle, LocaleDate, TimeStyle};
+//!
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap();
+//! assert_eq!(dt.formatl_style(DateStyle::Long, TimeStyle::Short, "de").to_string(), "8. Juli 2001, 00:34");
+//! assert_eq!(dt.formatl_style(DateStyle::Short, TimeStyle::Short, "en").to_string(), "7/8/01, 12:34 AM");
+//! assert_eq!(dt.formatl_date_style(DateStyle::Full, "fr").to_string(), "dimanche 8 juillet 2001");
+//! ```
+//!
+//! When the styles don't fit, [LocaleDate::formatl_skeleton] picks the locale's pattern for a
+//! CLDR skeleton, which lists the fields to write and their widths but leaves their order and
+//! punctuation to the locale:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap();
+//! assert_eq!(dt.formatl_skeleton("MMMd", "en").unwrap().to_string(), "Jul 8");
+//! assert_eq!(dt.formatl_skeleton("MMMd", "fr").unwrap().to_string(), "8 juil.");
+//! assert_eq!(dt.formatl_skeleton("MMMMEEEEd", "en").unwrap().to_string(), "Sunday, July 8");
+//! assert_eq!(dt.formatl_skeleton("yMMMdjm", "de").unwrap().to_string(), "8. Juli 2001, 00:34");
+//! ```
+//!
+//! Patterns written for ICU or found in CLDR, e.g. `EEEE d MMMM y`, are formatted with
+//! [LocaleDate::formatl_pattern], where text is quoted and the names are the locale's:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap();
+//! assert_eq!(dt.formatl_pattern("EEEE d MMMM y", "fr").to_string(), "dimanche 8 juillet 2001");
+//! assert_eq!(dt.formatl_pattern("QQQQ y, h 'o''clock' a", "en").to_string(), "3rd quarter 2001, 12 o'clock AM");
+//! ```
+//!
+//! The day of the month is written as an ordinal number with `%Eo`, following the rules of the
+//! locale, and with `%Ef` in its feminine form where the language has one ("1ª" in Spanish):
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 1).unwrap();
+//! assert_eq!(dt.formatl("%A, %B %Eo", "en").to_string(), "Sunday, July 1st");
+//! assert_eq!(dt.formatl("%A %Eo %B", "fr").to_string(), "dimanche 1er juillet");
+//! assert_eq!(dt.formatl("%A, %Eo %B", "de").to_string(), "Sonntag, 1. Juli");
+//! assert_eq!(dt.formatl("%Eo / %Ef", "es").to_string(), "1º / 1ª");
+//! ```
+//!
+//! Months written with the day of the month are named as in the locale's own patterns, which some
+//! languages inflect, and stand-alone otherwise:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! assert_eq!(dt.formatl("%-d %B %Y", "pl").to_string(), "8 lipca 2001");
+//! assert_eq!(dt.formatl("%B %Y", "pl").to_string(), "lipiec 2001");
+//! ```
+//!
+//! Intervals between two dates or times are formatted with [LocaleInterval::formatl_interval],
+//! which writes the fields shared by both ends only once, as the locale does:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! use chrono_lc::{IntervalStyle, LocaleInterval};
+//!
+//! let start = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 0).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2001, 7, 10).unwrap().and_hms_opt(1, 30, 0).unwrap();
+//! assert_eq!(start.formatl_interval(&end, IntervalStyle::Date, "en-GB").to_string(), "8–10 July 2001");
+//! assert_eq!(start.formatl_interval(&end, IntervalStyle::Date, "en").to_string(), "July 8 – 10, 2001");
+//!
+//! let end = start.date().and_hms_opt(1, 30, 0).unwrap();
+//! assert_eq!(start.formatl_interval(&end, IntervalStyle::Time, "en").to_string(), "12:34 – 1:30 AM");
+//! ```
+//!
+//! ## Time zones
+//!
+//! `formatl` formats a `DateTime` in the time zone its offset is from (see [ZoneOffset]), so that
+//! `%Z` writes the name the offset gives itself, e.g. "UTC" or "+09:30" for fixed offsets, and the
+//! zone can be named in the locale: `%EZ` for its specific name ("Pacific Daylight Time"), `%Ev` for
+//! its generic name ("Pacific Time") and `%EV` for its city ("Los Angeles", or "ロサンゼルス" in
+//! Japanese). Zones without a name in the locale are written in the localized GMT format of `%EG`
+//! ("GMT+09:30"), as CLDR does.
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! use chrono_lc::{LocaleDate, TimeStyle};
+//!
+//! let dt = Utc.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+//! assert_eq!(dt.formatl("%H:%M %Z", "en").to_string(), "00:34 UTC");
+//! assert_eq!(dt.formatl_time_style(TimeStyle::Full, "en").to_string(), "12:34:59 AM Coordinated Universal Time");
+//! ```
+//!
+//! With the `chrono-tz` feature, the offsets of `chrono_tz` know their zone, and `%Z` writes its
+//! abbreviation ("ACST"). `LocaleDateTz::formatl_in` converts to a zone and formats in it in one
+//! call:
+//!
+//! ```rust
+//! # #[cfg(feature = "chrono-tz")]
+//! # {
+//! # use chrono::prelude::*;
+//! use chrono_lc::LocaleDateTz;
+//! use chrono_tz::Europe::Paris;
+//!
+//! let dt = Utc.with_ymd_and_hms(2001, 1, 7, 23, 34, 59).unwrap();
+//! assert_eq!(dt.formatl_in(Paris, "%H:%M %Z", "fr").to_string(), "00:34 CET");
+//! assert_eq!(dt.formatl_in(Paris, "%EZ", "fr").to_string(), "heure normale d’Europe centrale");
+//! # }
+//! ```
+//!
+//! Whatever the zone, `%EO` writes the offset in the GMT format of the locale ("GMT+9:30",
+//! "UTC−3" in French, or just "GMT" for UTC) and `%EG` in its long form ("GMT+09:30"):
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! use chrono_lc::LocaleDate;
+//!
+//! let dt = FixedOffset::east_opt(34200).unwrap().with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+//! assert_eq!(dt.formatl("%H:%M %EO", "en").to_string(), "00:34 GMT+9:30");
+//! assert_eq!(dt.formatl("%H:%M %EG", "fr").to_string(), "00:34 UTC+09:30");
+//! ```
+//!
+//! ## Other date and time crates
+//!
+//! With the `time` feature, the `Date`, `Time`, `PrimitiveDateTime` and `OffsetDateTime` of the
+//! `time` crate implement [LocaleDate], and are formatted as the chrono types with the same fields:
+//!
+//! ```rust
+//! # #[cfg(feature = "time")]
+//! # {
+//! use chrono_lc::LocaleDate;
+//! use time::{Date, Month};
+//!
+//! let date = Date::from_calendar_date(2001, Month::July, 8).unwrap();
+//! assert_eq!(date.formatl("%A %-d %B %Y", "fr").to_string(), "dimanche 8 juillet 2001");
+//! # }
+//! ```
+//!
+//! With the `jiff` feature, so do the `civil::Date`, `civil::Time`, `civil::DateTime`, `Zoned` and
+//! `Timestamp` (in UTC) of the `jiff` crate. `Zoned` values keep their time zone: `%Z` writes the
+//! abbreviation found in jiff's time zone database, and `%EZ`, `%Ev` and `%EV` name the zone as
+//! for `chrono_tz`:
+//!
+//! ```rust
+//! # #[cfg(feature = "jiff")]
+//! # {
+//! use chrono_lc::LocaleDate;
+//!
+//! let zoned: jiff::Zoned = "2001-07-08T00:34:59[Europe/Paris]".parse().unwrap();
+//! assert_eq!(zoned.formatl("%H:%M %Z", "fr").to_string(), "00:34 CEST");
+//! assert_eq!(zoned.formatl("%EZ", "fr").to_string(), "heure d’été d’Europe centrale");
+//! # }
+//! ```
+//!
+//! ## Calendars
+//!
+//! Dates are formatted in the Gregorian calendar, unless another one is selected with the `ca`
+//! key of the locale's Unicode extension: `buddhist`, `chinese`, `hebrew`, `islamic-civil`,
+//! `islamic-umalqura`, `japanese`, `persian` or `roc`.
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2026, 9, 28).unwrap();
+//! assert_eq!(dt.formatl("%d %B %Y", "he-u-ca-hebrew").to_string(), "י״ז תשרי תשפ״ז");
+//! assert_eq!(dt.formatl("%d %B %Y", "en-u-ca-hebrew").to_string(), "17 Tishri 5787");
+//! assert_eq!(dt.formatl("%d %B %Y", "ar-u-ca-islamic-umalqura").to_string(), "17 ربيع الآخر 1448");
+//! assert_eq!(dt.formatl("%d %B %Y", "fa-u-ca-persian").to_string(), "۰۶ مهر ۱۴۰۵");
+//! ```
+//!
+//! The months of these calendars are named in the locales whose data has them: Hebrew months in
+//! Hebrew, Hijri months in Arabic and Persian and Persian months in Persian. Other locales, e.g.
+//! Japanese with the Hebrew calendar, write them in English.
+//!
+//! Eras are formatted with the POSIX `%EC` (era name), `%Ey` (year of the era) and `%EY` (the
+//! year with its era, as written in the locale) specifiers, along with `%EN` for the narrow
+//! era name:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! assert_eq!(dt.formatl("%EY%-m月%-d日", "ja-u-ca-japanese").to_string(), "平成13年7月8日");
+//! assert_eq!(dt.formatl("%EN%Ey", "ja-u-ca-japanese").to_string(), "H13");
+//! assert_eq!(dt.formatl("%EY", "zh-TW-u-ca-roc").to_string(), "民國90年");
+//! assert_eq!(dt.formatl("%EY", "en").to_string(), "2001 AD");
+//! ```
+//!
+//! In the Chinese calendar, `%EC` is the name of the year in the sexagenary cycle, `%Ey` its
+//! number in the cycle and `%EY` the year as written in the locale. `%Ez` is the animal of the
+//! zodiac for the Chinese year, in any calendar. Chinese dates are supported from 1900 to 2100:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! assert_eq!(dt.formatl("%EY%B%d", "zh-u-ca-chinese").to_string(), "2001辛巳年五月十八");
+//! assert_eq!(dt.formatl("%B %-d, %EY", "en-u-ca-chinese").to_string(), "Fifth Month 18, 2001(xin-si)");
+//! assert_eq!(dt.formatl("%Ez", "en").to_string(), "Snake");
+//! assert_eq!(dt.formatl("%Ez", "ja").to_string(), "巳");
+//! ```
+//!
+//! The animals are named in Chinese, Japanese, Korean and Thai, and in English in other locales
+//! (CLDR doesn't name them in e.g. Arabic, Hebrew or Persian).
+//!
+//! Other calendars can be used by implementing [calendar::Calendar], which converts dates and
+//! names their months and eras, and passing it to [DelayedFormatL10n::with_calendar]:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! use chrono_lc::calendar::{Calendar, CalendarFields};
+//!
+//! /// Fiscal years starting in April.
+//! #[derive(Debug)]
+//! struct Fiscal;
+//!
+//! impl Calendar for Fiscal {
+//!     fn fields(&self, date: &NaiveDate) -> CalendarFields {
+//!         let year = if date.month() >= 4 { date.year() + 1 } else { date.year() };
+//!         CalendarFields { year, ..CalendarFields::from(*date) }
+//!     }
+//! }
+//!
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! assert_eq!(dt.formatl("FY%Y %B", "en").with_calendar(Fiscal).to_string(), "FY2002 July");
+//! ```
+//!
+//! ## Numbering systems
+//!
+//! Numbers are written with the digits of the locale, e.g. ۱۴۰۵ in Persian, or with those selected
+//! with the `nu` key: `latn`, `arab`, `arabext`, `deva`, `beng`, `thai`, `hanidec` or `fullwide`.
+//! Literals of the format string and the RFC 2822 and 3339 formats always use ASCII digits:
+//!
+//! ```rust
+//! # use chrono::prelude::*;
+//! # use chrono_lc::LocaleDate;
+//! #
+//! let dt = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+//! assert_eq!(dt.formatl("%-d %B %Y", "fa").to_string(), "۸ ژوئیهٔ ۲۰۰۱");
+//! assert_eq!(dt.formatl("%-d %B %Y", "fa-u-nu-latn").to_string(), "8 ژوئیهٔ 2001");
+//! assert_eq!(dt.formatl("%d/%m/%Y", "hi-u-nu-deva").to_string(), "०८/०७/२००१");
+//! ```
+//!
 
 #[allow(unused_imports)]
 pub(crate) use lazy_static::lazy_static;
 
+pub mod calendar;
+pub(crate) mod datelike;
 pub(crate) mod fmt;
+pub(crate) mod formatter;
+pub(crate) mod interval;
+pub(crate) mod items;
+#[cfg(feature = "jiff")]
+pub(crate) mod jiff_crate;
+pub(crate) mod locale;
 pub(crate) mod locales;
+pub(crate) mod names;
+pub(crate) mod negotiate;
+pub(crate) mod numbering;
+pub(crate) mod plural;
+pub(crate) mod skeleton;
+pub(crate) mod style;
+#[cfg(feature = "time")]
+pub(crate) mod time_crate;
 pub(crate) mod util;
+pub(crate) mod zone;
 
-pub use crate::fmt::format_l10n;
+pub use crate::datelike::{formatl_datelike, to_naive_date_time};
+pub use crate::fmt::{format_l10n, format_l10n_with_calendar};
+pub use crate::formatter::{DelayedFormatterL10n, InvalidFormatError, LocalizedFormatter};
+pub use crate::interval::{DelayedIntervalL10n, IntervalStyle, LocaleInterval};
+pub use crate::items::{FixedL10n, IcuItemsL10n, ItemL10n, StrftimeItemsL10n};
+pub use crate::names::{LocaleName, NameWidth};
+pub use crate::negotiate::{locale_from_accept_language, locale_from_env};
+pub use crate::style::{DateStyle, TimeStyle};
+pub use crate::zone::ZoneOffset;
 
-use chrono::{
-	format::{Item, StrftimeItems},
-	FixedOffset, NaiveDate, NaiveTime, Offset, TimeZone,
-};
+use std::{iter, sync::Arc, vec};
+
+use crate::{calendar::Calendar, locale::Locale, zone::Zone};
+use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone};
 
 pub trait LocaleDate {
-	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItems<'a>>;
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>>;
+
+	/// Formats in the user's locale, as found in the environment by [locale_from_env].
+	fn formatl_env<'a>(&self, fmt: &'a str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		self.formatl(fmt, &locale_from_env())
+	}
+
+	/// Formats like [LocaleDate::formatl], but into a value which doesn't borrow the format string.
+	fn formatl_owned(&self, fmt: &str, locale: &str) -> OwnedDelayedFormatL10n {
+		self.formatl(fmt, locale).into_owned()
+	}
+
+	/// Formats with the locale's date and time patterns of the given styles, joined as the locale
+	/// joins them for the date style, e.g. "8. Juli 2001, 00:34" in German.
+	fn formatl_style(&self, date: DateStyle, time: TimeStyle, locale: &str) -> DelayedFormatL10n<iter::Once<ItemL10n<'static>>> {
+		self.formatl("", locale)
+			.with_items(iter::once(ItemL10n::Fixed(FixedL10n::StyledDateTime(date, time))))
+	}
+
+	/// Formats the date with the locale's pattern of the given style, e.g. "8 juillet 2001".
+	fn formatl_date_style(&self, style: DateStyle, locale: &str) -> DelayedFormatL10n<iter::Once<ItemL10n<'static>>> {
+		self.formatl("", locale)
+			.with_items(iter::once(ItemL10n::Fixed(FixedL10n::StyledDate(style))))
+	}
+
+	/// Formats the time with the locale's pattern of the given style, e.g. "00:34".
+	fn formatl_time_style(&self, style: TimeStyle, locale: &str) -> DelayedFormatL10n<iter::Once<ItemL10n<'static>>> {
+		self.formatl("", locale)
+			.with_items(iter::once(ItemL10n::Fixed(FixedL10n::StyledTime(style))))
+	}
+
+	/// Formats with the locale's pattern for a skeleton, which lists the fields to write and their
+	/// widths with the symbols of CLDR, e.g. "Jul 8" in English and "8 juil." in French for `MMMd`.
+	/// The symbols are `G`, `y`, `M` (or `L`), `d`, `E` (or `c`), `h` (or `K`), `H` (or `k`), `j`
+	/// (the hour of the locale's clock), `m`, `s`, `z` and `v`. Hours are written on the other clock
+	/// if the locale has no pattern on the requested one. Returns `None` for empty skeletons, other
+	/// symbols and fields the locale has no pattern for.
+	fn formatl_skeleton(&self, skeleton: &str, locale: &str) -> Option<OwnedDelayedFormatL10n> {
+		let items = skeleton::items(skeleton, &Locale::parse(locale).name)?;
+		Some(self.formatl("", locale).with_items(items.into_iter()))
+	}
+
+	/// Formats with an ICU pattern, as found in CLDR and in other platforms' date formatters, e.g.
+	/// `EEEE d MMMM y` or `h 'o''clock' a` (see [IcuItemsL10n] for its fields).
+	fn formatl_pattern<'a>(&self, pattern: &'a str, locale: &str) -> DelayedFormatL10n<IcuItemsL10n<'a>> {
+		self.formatl("", locale).with_items(IcuItemsL10n::new(pattern))
+	}
 }
 
 impl LocaleDate for chrono::NaiveDate {
-	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItems<'a>> {
-		DelayedFormatL10n::new(Some(*self), None, StrftimeItems::new(fmt), locale)
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(Some(*self), None, StrftimeItemsL10n::new(fmt), locale)
+	}
+}
+
+impl LocaleDate for chrono::NaiveTime {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(None, Some(*self), StrftimeItemsL10n::new(fmt), locale)
 	}
 }
 
 impl LocaleDate for chrono::NaiveDateTime {
-	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItems<'a>> {
-		DelayedFormatL10n::new(Some(self.date()), Some(self.time()), StrftimeItems::new(fmt), locale)
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(Some(self.date()), Some(self.time()), StrftimeItemsL10n::new(fmt), locale)
 	}
 }
 
-impl<Tz: TimeZone> LocaleDate for chrono::DateTime<Tz> {
-	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItems<'a>> {
+/// Formats in the time zone the offset of the date is from, for the offsets of chrono and, with the
+/// `chrono-tz` feature, of `chrono_tz::Tz`: `%Z` writes the name of the offset ("UTC", "ACST") and
+/// `%EZ`, `%Ev` and `%EV` name its zone in the locale. Other offsets are written as fixed ones,
+/// e.g. "+09:30" for `%Z`, unless given to [DelayedFormatL10n::with_zone].
+impl<Tz: TimeZone> LocaleDate for chrono::DateTime<Tz>
+where
+	Tz::Offset: 'static,
+{
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
 		let local = self.naive_local();
-		let offset = self.offset().fix();
-		DelayedFormatL10n::new_with_offset(Some(local.date()), Some(local.time()), &offset, StrftimeItems::new(fmt), locale)
+		let (off, zone) = zone::describe_any(self.offset());
+		DelayedFormatL10n {
+			off: Some(off),
+			zone,
+			..DelayedFormatL10n::new(Some(local.date()), Some(local.time()), StrftimeItemsL10n::new(fmt), locale)
+		}
 	}
 }
 
+/// Formats in UTC, as `DateTime<Utc>` does.
+impl LocaleDate for std::time::SystemTime {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		chrono::DateTime::<chrono::Utc>::from(*self).formatl(fmt, locale)
+	}
+}
+
+/// Formatting in the time zones of the IANA database, with the `chrono-tz` feature.
+#[cfg(feature = "chrono-tz")]
+pub trait LocaleDateTz {
+	/// Converts to the time zone `tz`, e.g. the user's, and formats in it.
+	fn formatl_in<'a>(&self, tz: chrono_tz::Tz, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>>;
+}
+
+#[cfg(feature = "chrono-tz")]
+impl<Tz: TimeZone> LocaleDateTz for chrono::DateTime<Tz> {
+	fn formatl_in<'a>(&self, tz: chrono_tz::Tz, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		self.with_timezone(&tz).formatl(fmt, locale)
+	}
+}
+
+/// A [DelayedFormatL10n] which owns its items and borrows nothing, constructed via
+/// [LocaleDate::formatl_owned] or [DelayedFormatL10n::into_owned].
+pub type OwnedDelayedFormatL10n = DelayedFormatL10n<vec::IntoIter<ItemL10n<'static>>>;
+
 /// A *temporary* object which can be used as an argument to `format!` or others.
 /// This is normally constructed via `format` methods of each date and time type.
 #[derive(Debug)]
@@ -105,19 +550,29 @@ pub struct DelayedFormatL10n<I> {
 	time: Option<NaiveTime>,
 	/// The name and local-to-UTC difference for the offset (timezone), if any.
 	off: Option<(String, FixedOffset)>,
+	/// The time zone the offset is from, if known.
+	zone: Option<Zone>,
 	/// An iterator returning formatting items.
 	items: I,
+	/// The calendar to format the date in, instead of the one selected by the locale.
+	calendar: Option<Arc<dyn Calendar>>,
 }
 
-impl<'a, I: Iterator<Item = Item<'a>> + Clone> DelayedFormatL10n<I> {
+impl<'a, I> DelayedFormatL10n<I>
+where
+	I: Iterator + Clone,
+	I::Item: Into<ItemL10n<'a>>,
+{
 	/// Makes a new `DelayedFormatL10n` value out of local date and time.
 	pub fn new(date: Option<NaiveDate>, time: Option<NaiveTime>, items: I, locale: &str) -> DelayedFormatL10n<I> {
 		DelayedFormatL10n {
 			date,
 			time,
 			off: None,
+			zone: None,
 			items,
 			locale: locale.to_owned(),
+			calendar: None,
 		}
 	}
 
@@ -128,21 +583,73 @@ impl<'a, I: Iterator<Item = Item<'a>> + Clone> DelayedFormatL10n<I> {
 			date,
 			time,
 			off: Some(name_and_diff),
+			zone: None,
 			items,
 			locale: locale.to_owned(),
+			calendar: None,
+		}
+	}
+
+	/// Formats the date in the given calendar, instead of the one selected by the locale.
+	pub fn with_calendar<C: Calendar + 'static>(self, calendar: C) -> DelayedFormatL10n<I> {
+		DelayedFormatL10n {
+			calendar: Some(Arc::new(calendar)),
+			..self
+		}
+	}
+
+	/// Formats with the time zone `offset` is from, as [LocaleDate::formatl] does for the
+	/// `DateTime`s of chrono and chrono-tz, e.g. for offsets of other crates which implement
+	/// [ZoneOffset].
+	pub fn with_zone<O: ZoneOffset>(self, offset: &O) -> DelayedFormatL10n<I> {
+		let (off, zone) = zone::describe(offset);
+		DelayedFormatL10n {
+			off: Some(off),
+			zone,
+			..self
+		}
+	}
+
+	/// Converts into a value which owns its items, so that it can outlive the format string, e.g.
+	/// to be returned by a function building the format string or stored in a struct.
+	pub fn into_owned(self) -> OwnedDelayedFormatL10n {
+		let items: Vec<ItemL10n<'static>> = self.items.clone().map(|item| item.into().to_owned()).collect();
+		self.with_items(items.into_iter())
+	}
+}
+
+impl<I> DelayedFormatL10n<I> {
+	/// Formats other items, with the same date, time, locale and calendar.
+	fn with_items<J>(self, items: J) -> DelayedFormatL10n<J> {
+		DelayedFormatL10n {
+			locale: self.locale,
+			date: self.date,
+			time: self.time,
+			off: self.off,
+			zone: self.zone,
+			items,
+			calendar: self.calendar,
 		}
 	}
 }
 
-impl<'a, I: Iterator<Item = Item<'a>> + Clone> std::fmt::Display for DelayedFormatL10n<I> {
+impl<'a, I> std::fmt::Display for DelayedFormatL10n<I>
+where
+	I: Iterator + Clone,
+	I::Item: Into<ItemL10n<'a>>,
+{
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		format_l10n(
+		let locale = Locale::parse(&self.locale);
+		crate::fmt::format_items(
 			f,
 			self.date.as_ref(),
 			self.time.as_ref(),
 			self.off.as_ref(),
+			self.zone.as_ref(),
 			self.items.clone(),
-			&self.locale,
+			&locale,
+			self.calendar.as_deref().unwrap_or(&locale.calendar),
+			0,
 		)
 	}
 }
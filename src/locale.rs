@@ -0,0 +1,50 @@
+use crate::{calendar::CalendarKind, numbering::NumberingSystem, util};
+
+/// A locale, as passed to `formatl`, split into the name used to search translations and the
+/// options set through its Unicode extension (`-u-<key>-<value>`), e.g. `he-u-ca-hebrew`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Locale {
+	/// The normalised name, e.g. `pt-br` for `pt_BR`.
+	pub name: String,
+	/// The calendar selected with the `ca` key.
+	pub calendar: CalendarKind,
+	/// The numbering system selected with the `nu` key, or the default one of the locale.
+	pub numbering: NumberingSystem,
+}
+
+impl Locale {
+	pub fn parse(locale: &str) -> Locale {
+		let locale = locale.to_lowercase().replace('_', "-");
+
+		// the name ends at the first extension, which starts with a single letter subtag
+		let name = locale.split('-').take_while(|subtag| subtag.len() != 1).collect::<Vec<&str>>().join("-");
+		let numbering = util::numbering_system(&name).and_then(NumberingSystem::from_id).unwrap_or_default();
+		let mut res = Locale {
+			name,
+			numbering,
+			..Locale::default()
+		};
+
+		// keys are followed by one or more subtags, e.g. `ca-islamic-umalqura`
+		let mut keywords: Vec<(&str, Vec<&str>)> = Vec::new();
+		for subtag in locale.split('-').skip_while(|&subtag| subtag != "u").skip(1) {
+			match (subtag.len(), keywords.last_mut()) {
+				// another extension
+				(1, _) => break,
+				(2, _) => keywords.push((subtag, Vec::new())),
+				(_, Some((_, values))) => values.push(subtag),
+				_ => {}
+			}
+		}
+
+		for (key, values) in keywords {
+			match key {
+				"ca" => res.calendar = CalendarKind::from_id(&values.join("-")).unwrap_or_default(),
+				"nu" => res.numbering = NumberingSystem::from_id(&values.join("-")).unwrap_or(res.numbering),
+				_ => {}
+			}
+		}
+
+		res
+	}
+}
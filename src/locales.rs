@@ -1,13 +1,77 @@
 use crate::lazy_static;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Locales {
+	/// The names of the locales with data of their own, e.g. `en-gb`.
+	pub names: HashSet<String>,
 	pub short_months: HashMap<String, Vec<&'static str>>,
 	pub long_months: HashMap<String, Vec<&'static str>>,
 	pub short_weekdays: HashMap<String, Vec<&'static str>>,
 	pub long_weekdays: HashMap<String, Vec<&'static str>>,
+	pub narrow_months: HashMap<String, Vec<&'static str>>,
+	/// The names of the months as written with a day, where they differ from the stand-alone ones
+	/// (e.g. "lipca" rather than "lipiec" in Polish).
+	pub format_short_months: HashMap<String, Vec<&'static str>>,
+	pub format_long_months: HashMap<String, Vec<&'static str>>,
+	pub short_quarters: HashMap<String, Vec<&'static str>>,
+	pub long_quarters: HashMap<String, Vec<&'static str>>,
 	pub ampm: HashMap<String, Vec<&'static str>>,
+	pub hebrew_short_months: HashMap<String, Vec<&'static str>>,
+	pub hebrew_long_months: HashMap<String, Vec<&'static str>>,
+	pub hijri_short_months: HashMap<String, Vec<&'static str>>,
+	pub hijri_long_months: HashMap<String, Vec<&'static str>>,
+	pub persian_short_months: HashMap<String, Vec<&'static str>>,
+	pub persian_long_months: HashMap<String, Vec<&'static str>>,
+	pub chinese_short_months: HashMap<String, Vec<&'static str>>,
+	pub chinese_long_months: HashMap<String, Vec<&'static str>>,
+	pub chinese_days: HashMap<String, Vec<&'static str>>,
+	pub chinese_cyclic_years: HashMap<String, Vec<&'static str>>,
+	pub chinese_zodiacs: HashMap<String, Vec<&'static str>>,
+	pub ordinal_rules: HashMap<String, Vec<&'static str>>,
+	pub ordinal_day_fmt: HashMap<String, Vec<&'static str>>,
+	pub ordinal_day_fmt_feminine: HashMap<String, Vec<&'static str>>,
+	pub date_interval_fmt: HashMap<String, Vec<&'static str>>,
+	pub time_interval_fmt: HashMap<String, Vec<&'static str>>,
+	pub date_formats: HashMap<String, Vec<&'static str>>,
+	pub time_formats: HashMap<String, Vec<&'static str>>,
+	pub date_time_formats: HashMap<String, Vec<&'static str>>,
+	pub available_formats: HashMap<String, Vec<(&'static str, &'static str)>>,
+	pub zone_standard_names: HashMap<String, Vec<&'static str>>,
+	pub zone_daylight_names: HashMap<String, Vec<&'static str>>,
+	pub zone_generic_names: HashMap<String, Vec<&'static str>>,
+	pub zone_exemplar_cities: HashMap<String, Vec<(&'static str, &'static str)>>,
+	/// The CLDR metazone of each time zone, e.g. `America_Pacific` for `America/Los_Angeles`, sorted
+	/// by zone.
+	pub metazones: Vec<(&'static str, &'static str)>,
+	pub gmt_offset_fmt: HashMap<String, Vec<&'static str>>,
+	pub short_eras: HashMap<String, Vec<&'static str>>,
+	pub narrow_eras: HashMap<String, Vec<&'static str>>,
+	pub hebrew_short_eras: HashMap<String, Vec<&'static str>>,
+	pub hebrew_narrow_eras: HashMap<String, Vec<&'static str>>,
+	pub hijri_short_eras: HashMap<String, Vec<&'static str>>,
+	pub hijri_narrow_eras: HashMap<String, Vec<&'static str>>,
+	pub persian_short_eras: HashMap<String, Vec<&'static str>>,
+	pub persian_narrow_eras: HashMap<String, Vec<&'static str>>,
+	pub buddhist_short_eras: HashMap<String, Vec<&'static str>>,
+	pub buddhist_narrow_eras: HashMap<String, Vec<&'static str>>,
+	pub roc_short_eras: HashMap<String, Vec<&'static str>>,
+	pub roc_narrow_eras: HashMap<String, Vec<&'static str>>,
+	pub japanese_short_eras: HashMap<String, Vec<&'static str>>,
+	pub japanese_narrow_eras: HashMap<String, Vec<&'static str>>,
+	pub d_fmt: HashMap<String, &'static str>,
+	pub t_fmt: HashMap<String, &'static str>,
+	pub d_t_fmt: HashMap<String, &'static str>,
+	pub t_fmt_ampm: HashMap<String, &'static str>,
+	pub era_year_fmt: HashMap<String, &'static str>,
+	pub first_era_year_fmt: HashMap<String, &'static str>,
+	pub chinese_leap_month_fmt: HashMap<String, &'static str>,
+	pub chinese_year_fmt: HashMap<String, &'static str>,
+	pub numbering_system: HashMap<String, &'static str>,
+	pub date_time_interval_fmt: HashMap<String, &'static str>,
+	pub interval_fallback_fmt: HashMap<String, &'static str>,
+	pub gmt_fmt: HashMap<String, &'static str>,
+	pub gmt_zero_fmt: HashMap<String, &'static str>,
 }
 
 #[rustfmt::skip]
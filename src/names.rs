@@ -0,0 +1,42 @@
+//! The names of chrono's weekdays and months in each locale.
+
+use chrono::{Month, Weekday};
+
+use crate::{locale::Locale, util};
+
+/// The width of a name, e.g. "Sun" or "Sunday".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NameWidth {
+	/// The abbreviated name, as written by `%a` and `%b`.
+	Short,
+	/// The full name, as written by `%A` and `%B`.
+	Long,
+}
+
+/// The name of a weekday or a month in a locale, e.g. "dimanche" or "janvier".
+pub trait LocaleName {
+	/// Returns the name in the locale, e.g. "dimanche" for [Weekday::Sun] in French.
+	fn name_l(&self, locale: &str, width: NameWidth) -> &'static str;
+}
+
+impl LocaleName for Weekday {
+	fn name_l(&self, locale: &str, width: NameWidth) -> &'static str {
+		let (key, locale) = (self.num_days_from_monday() as usize, &Locale::parse(locale).name);
+		match width {
+			NameWidth::Short => util::short_weekday(key, locale),
+			NameWidth::Long => util::long_weekday(key, locale),
+		}
+	}
+}
+
+impl LocaleName for Month {
+	/// Returns the stand-alone name of the month in the Gregorian calendar, whatever the calendar
+	/// of the locale.
+	fn name_l(&self, locale: &str, width: NameWidth) -> &'static str {
+		let (key, locale) = (self.number_from_month() as usize - 1, &Locale::parse(locale).name);
+		match width {
+			NameWidth::Short => util::short_month(key, locale),
+			NameWidth::Long => util::long_month(key, locale),
+		}
+	}
+}
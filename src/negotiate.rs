@@ -0,0 +1,66 @@
+use std::env;
+
+use crate::util;
+
+/// Finds the best supported locale for the user from the environment, the same way `setlocale`
+/// does for `LC_TIME`: the first of `LC_ALL`, `LC_TIME` and `LANG` which is set is used.
+/// If it isn't supported, the languages listed in `LANGUAGE` are tried in order.
+///
+/// POSIX locale names such as `fr_FR.UTF-8@euro` are normalised to the form used by this crate
+/// (`fr-fr`, or `fr` if only the language is supported). `C` is returned when nothing matches.
+pub fn locale_from_env() -> String {
+	let var = |name| env::var(name).ok().filter(|value| !value.is_empty());
+	let locale = var("LC_ALL").or_else(|| var("LC_TIME")).or_else(|| var("LANG"));
+	let locale = locale.as_deref().and_then(normalize_posix_locale);
+	if locale.as_deref() == Some("C") {
+		return "C".to_owned();
+	}
+
+	let languages = var("LANGUAGE").unwrap_or_default();
+	locale
+		.into_iter()
+		.chain(languages.split(':').filter_map(normalize_posix_locale))
+		.find_map(|locale| util::available_locale(&locale).map(str::to_owned))
+		.unwrap_or_else(|| "C".to_owned())
+}
+
+/// Converts a POSIX locale name (`language[_territory][.codeset][@modifier]`) to the form used by
+/// this crate, dropping the codeset and modifier.
+fn normalize_posix_locale(locale: &str) -> Option<String> {
+	let name = locale.split(['.', '@']).next().unwrap_or_default();
+	match name {
+		"" => None,
+		"C" | "POSIX" => Some("C".to_owned()),
+		name => Some(name.to_lowercase().replace('_', "-")),
+	}
+}
+
+/// Finds the best supported locale for the languages listed in an HTTP `Accept-Language` header,
+/// such as `fr-CH, fr;q=0.9, en;q=0.8`.
+///
+/// Languages are tried from the highest to the lowest quality, and each of them matches the same
+/// way translations are searched by `formatl`: `fr-CH` matches `fr` if there is no `fr-ch` locale.
+/// `C` is returned when nothing matches, or when the wildcard `*` is reached.
+pub fn locale_from_accept_language(header: &str) -> String {
+	let mut languages = header
+		.split(',')
+		.filter_map(|range| {
+			let mut parts = range.split(';').map(str::trim);
+			let language = parts.next().filter(|language| !language.is_empty())?;
+			let quality = parts
+				.find_map(|param| param.strip_prefix("q=").or_else(|| param.strip_prefix("Q=")))
+				.map_or(Some(1.0), |q| q.parse::<f32>().ok())?;
+			Some((language, quality))
+		})
+		.filter(|&(_, quality)| quality > 0.0)
+		.collect::<Vec<(&str, f32)>>();
+	// the sort is stable, so languages with the same quality keep their order
+	languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+	languages
+		.into_iter()
+		.map(|(language, _)| language.to_lowercase().replace('_', "-"))
+		.take_while(|language| language != "*")
+		.find_map(|language| util::available_locale(&language).map(str::to_owned))
+		.unwrap_or_else(|| "C".to_owned())
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+/// The numbering systems numbers can be written in, as selected with the `nu` key of the locale
+/// or by its default (`numbering_system` in the locale data).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) enum NumberingSystem {
+	/// ASCII digits.
+	#[default]
+	Latn,
+	/// Arabic-Indic digits, e.g. ٠١٢.
+	Arab,
+	/// Extended Arabic-Indic digits, as used in Persian and Urdu, e.g. ۰۱۲.
+	Arabext,
+	/// Devanagari digits, e.g. ०१२.
+	Deva,
+	/// Bengali digits, e.g. ০১২.
+	Beng,
+	/// Thai digits, e.g. ๐๑๒.
+	Thai,
+	/// Chinese decimal digits, e.g. 〇一二.
+	Hanidec,
+	/// Full-width digits, e.g. ０１２.
+	Fullwide,
+}
+
+impl NumberingSystem {
+	/// Returns the numbering system with the given CLDR identifier (e.g. `arab`), if supported.
+	pub fn from_id(id: &str) -> Option<NumberingSystem> {
+		match id {
+			"latn" => Some(NumberingSystem::Latn),
+			"arab" => Some(NumberingSystem::Arab),
+			"arabext" => Some(NumberingSystem::Arabext),
+			"deva" => Some(NumberingSystem::Deva),
+			"beng" => Some(NumberingSystem::Beng),
+			"thai" => Some(NumberingSystem::Thai),
+			"hanidec" => Some(NumberingSystem::Hanidec),
+			"fullwide" => Some(NumberingSystem::Fullwide),
+			_ => None,
+		}
+	}
+
+	/// Returns the digit for a value from 0 to 9.
+	fn digit(self, value: u32) -> char {
+		// all of them but the Chinese digits follow each other in Unicode
+		let zero = match self {
+			NumberingSystem::Latn => '0',
+			NumberingSystem::Arab => '\u{660}',
+			NumberingSystem::Arabext => '\u{6f0}',
+			NumberingSystem::Deva => '\u{966}',
+			NumberingSystem::Beng => '\u{9e6}',
+			NumberingSystem::Thai => '\u{e50}',
+			NumberingSystem::Hanidec => return ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'][value as usize],
+			NumberingSystem::Fullwide => '\u{ff10}',
+		};
+		char::from_u32(zero as u32 + value).expect("digits are valid characters")
+	}
+}
+
+/// A writer replacing the ASCII digits written to it with those of a numbering system.
+pub(crate) struct NativeDigits<'a, W: ?Sized> {
+	inner: &'a mut W,
+	system: NumberingSystem,
+}
+
+impl<'a, W: fmt::Write + ?Sized> NativeDigits<'a, W> {
+	pub fn new(inner: &'a mut W, system: NumberingSystem) -> NativeDigits<'a, W> {
+		NativeDigits { inner, system }
+	}
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for NativeDigits<'_, W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		if self.system == NumberingSystem::Latn {
+			return self.inner.write_str(s);
+		}
+		for c in s.chars() {
+			if c.is_ascii_digit() {
+				self.inner.write_char(self.system.digit(c as u32 - '0' as u32))?;
+			} else {
+				self.inner.write_char(c)?;
+			}
+		}
+		Ok(())
+	}
+}
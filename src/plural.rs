@@ -0,0 +1,81 @@
+//! CLDR plural rules, e.g. `n % 10 = 1 and n % 100 != 11`, as they apply to whole numbers.
+
+/// The plural categories of CLDR, in the order of the lists of the locale data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum PluralCategory {
+	Zero,
+	One,
+	Two,
+	Few,
+	Many,
+	Other,
+}
+
+impl PluralCategory {
+	/// The categories with a rule, i.e. all but [PluralCategory::Other].
+	const RULED: [PluralCategory; 5] = [
+		PluralCategory::Zero,
+		PluralCategory::One,
+		PluralCategory::Two,
+		PluralCategory::Few,
+		PluralCategory::Many,
+	];
+
+	/// Returns the category of a number, given the rules of the other categories in order (see
+	/// [PluralCategory::index]). Empty rules never match.
+	pub fn of<'a>(n: u64, rules: impl Fn(usize) -> &'a str) -> PluralCategory {
+		PluralCategory::RULED
+			.into_iter()
+			.find(|category| matches(rules(category.index()), n))
+			.unwrap_or(PluralCategory::Other)
+	}
+
+	/// Returns the index of the category in the lists of the locale data, starting from 0 for zero.
+	pub fn index(self) -> usize {
+		self as usize
+	}
+}
+
+/// Returns whether a number matches a rule. Samples (after `@`) are ignored, and rules which can't
+/// be parsed never match.
+fn matches(rule: &str, n: u64) -> bool {
+	let rule = rule.split('@').next().unwrap_or_default();
+	!rule.trim().is_empty()
+		&& rule
+			.split(" or ")
+			.any(|condition| condition.split(" and ").all(|relation| relation_matches(relation, n).unwrap_or(false)))
+}
+
+/// Evaluates a relation such as `n % 100 != 11..13,15`.
+fn relation_matches(relation: &str, n: u64) -> Option<bool> {
+	let (expr, ranges, negated) = match relation.split_once("!=") {
+		Some((expr, ranges)) => (expr, ranges, true),
+		None => {
+			let (expr, ranges) = relation.split_once('=')?;
+			(expr, ranges, false)
+		}
+	};
+
+	let value = match expr.split_once('%') {
+		Some((operand, modulus)) => operand_value(operand, n)?.checked_rem(modulus.trim().parse().ok()?)?,
+		None => operand_value(expr, n)?,
+	};
+
+	let mut found = false;
+	for range in ranges.split(',') {
+		let (start, end) = range.split_once("..").unwrap_or((range, range));
+		let (start, end): (u64, u64) = (start.trim().parse().ok()?, end.trim().parse().ok()?);
+		found |= (start..=end).contains(&value);
+	}
+	Some(found != negated)
+}
+
+/// Returns the value of an operand for a whole number: its absolute value (`n`) and integer part
+/// (`i`), or 0 for the operands about fraction digits and exponents.
+fn operand_value(operand: &str, n: u64) -> Option<u64> {
+	match operand.trim() {
+		"n" | "i" => Some(n),
+		"v" | "w" | "f" | "t" | "c" | "e" => Some(0),
+		_ => None,
+	}
+}
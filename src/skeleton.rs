@@ -0,0 +1,197 @@
+//! Skeletons, e.g. `yMMMd`, which list the fields of a date and their widths but leave their order
+//! and punctuation to the locale, as the flexible date formats of CLDR do.
+
+use chrono::format::{Fixed, Item, Numeric, Pad};
+
+use crate::{
+	items::{FixedL10n, ItemL10n, StrftimeItemsL10n},
+	style::{DateStyle, TimeStyle},
+	util,
+};
+
+/// A field of a skeleton: its symbol, e.g. `M`, and how many times it's repeated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Field {
+	symbol: char,
+	width: usize,
+}
+
+impl Field {
+	/// Returns whether the field is written with words rather than numbers, e.g. `MMM`.
+	fn is_text(self) -> bool {
+		matches!(self.symbol, 'G' | 'E' | 'z' | 'v') || (self.symbol == 'M' && self.width >= 3)
+	}
+
+	fn is_date(self) -> bool {
+		matches!(self.symbol, 'G' | 'y' | 'M' | 'd' | 'E')
+	}
+
+	fn is_zone(self) -> bool {
+		matches!(self.symbol, 'z' | 'v')
+	}
+}
+
+/// Returns the items of the locale's pattern that best matches a skeleton, with the widths of the
+/// skeleton, or `None` for empty skeletons, unknown symbols and fields the locale has no pattern
+/// for.
+pub(crate) fn items(skeleton: &str, locale: &str) -> Option<Vec<ItemL10n<'static>>> {
+	fields_items(&parse(skeleton, locale)?, locale)
+}
+
+fn fields_items(fields: &[Field], locale: &str) -> Option<Vec<ItemL10n<'static>>> {
+	// zones are appended to the pattern of the other fields
+	let (zones, fields): (Vec<Field>, Vec<Field>) = fields.iter().partition(|field| field.is_zone());
+	let mut items = if fields.is_empty() {
+		Vec::new()
+	} else if let Some(pattern) = best_pattern(&fields, locale) {
+		pattern_items(pattern, &fields)
+	} else {
+		// dates and times are matched on their own, then joined as the locale does
+		let (date, time): (Vec<Field>, Vec<Field>) = fields.iter().partition(|field| field.is_date());
+		if date.is_empty() || time.is_empty() {
+			return None;
+		}
+		let (date_pattern, time_pattern) = (best_pattern(&date, locale)?, best_pattern(&time, locale)?);
+
+		let mut items = Vec::new();
+		let mut rest = util::date_time_format(date_style(&date).index(), locale);
+		while let Some(start) = rest.find('{') {
+			items.push(Item::Literal(&rest[..start]).into());
+			let (index, tail) = rest[start + 1..].split_once('}')?;
+			items.extend(match index {
+				"0" => pattern_items(time_pattern, &time),
+				_ => pattern_items(date_pattern, &date),
+			});
+			rest = tail;
+		}
+		items.push(Item::Literal(rest).into());
+		items
+	};
+
+	if let Some(zone) = zones.first() {
+		if !items.is_empty() {
+			items.push(Item::Space(" ").into());
+		}
+		items.push(match (zone.symbol, zone.width) {
+			('z', 4) => ItemL10n::Fixed(FixedL10n::ZoneName),
+			('v', 4) => ItemL10n::Fixed(FixedL10n::GenericZoneName),
+			_ => Item::Fixed(Fixed::TimezoneName).into(),
+		});
+	}
+	Some(items)
+}
+
+/// Parses a skeleton, where `j` stands for the hour of the locale's clock and the hours counted
+/// from 0 to 11 (`K`) and from 1 to 24 (`k`) for those of the 12-hour and 24-hour clocks. Returns
+/// `None` for symbols that skeletons can't have.
+fn parse(skeleton: &str, locale: &str) -> Option<Vec<Field>> {
+	let mut fields: Vec<Field> = Vec::new();
+	for c in skeleton.chars() {
+		let symbol = match c {
+			'L' => 'M',
+			'c' => 'E',
+			'j' => preferred_hour(locale),
+			'K' => 'h',
+			'k' => 'H',
+			'G' | 'y' | 'M' | 'd' | 'E' | 'a' | 'h' | 'H' | 'm' | 's' | 'z' | 'v' => c,
+			_ => return None,
+		};
+		match fields.last_mut() {
+			Some(field) if field.symbol == symbol => field.width += 1,
+			_ => fields.push(Field { symbol, width: 1 }),
+		}
+	}
+	// the period of the day comes with the 12-hour clock
+	fields.retain(|field| field.symbol != 'a');
+	(!fields.is_empty()).then_some(fields)
+}
+
+/// Returns the symbol of the hour of the locale's clock, as found in its short time pattern.
+fn preferred_hour(locale: &str) -> char {
+	if util::time_format(TimeStyle::Short.index(), locale).contains('I') {
+		'h'
+	} else {
+		'H'
+	}
+}
+
+/// Returns the locale's pattern whose skeleton has the same fields, with the closest widths. When
+/// the locale has no such pattern on the requested clock, the hours of the other clock are matched,
+/// e.g. `Hm` gets the `hm` pattern of a locale writing times on the 12-hour clock only.
+fn best_pattern(fields: &[Field], locale: &str) -> Option<&'static str> {
+	closest_pattern(fields, locale).or_else(|| {
+		let other_clock: Vec<Field> = fields
+			.iter()
+			.map(|field| match field.symbol {
+				'h' => Field { symbol: 'H', ..*field },
+				'H' => Field { symbol: 'h', ..*field },
+				_ => *field,
+			})
+			.collect();
+		(other_clock != fields).then(|| closest_pattern(&other_clock, locale)).flatten()
+	})
+}
+
+/// Returns the locale's pattern whose skeleton has exactly the same fields, with the closest widths.
+fn closest_pattern(fields: &[Field], locale: &str) -> Option<&'static str> {
+	util::available_formats(locale)
+		.iter()
+		.filter_map(|&(skeleton, pattern)| Some((distance(fields, &parse(skeleton, locale)?)?, pattern)))
+		.min_by_key(|&(distance, _)| distance)
+		.map(|(_, pattern)| pattern)
+}
+
+/// Returns how far the widths of the fields of a skeleton are from the requested ones, with words
+/// and numbers being the furthest apart, or `None` if they don't have the same fields.
+fn distance(requested: &[Field], available: &[Field]) -> Option<usize> {
+	if requested.len() != available.len() {
+		return None;
+	}
+	requested
+		.iter()
+		.map(|field| {
+			let other = available.iter().find(|other| other.symbol == field.symbol)?;
+			Some(if field.is_text() != other.is_text() {
+				0x100
+			} else {
+				field.width.abs_diff(other.width)
+			})
+		})
+		.sum()
+}
+
+/// Returns the style of the date whose patterns are joined to times as the fields of a date are.
+fn date_style(date: &[Field]) -> DateStyle {
+	let month = date.iter().find(|field| field.symbol == 'M').map_or(0, |field| field.width);
+	let weekday = date.iter().any(|field| field.symbol == 'E');
+	match month {
+		4.. if weekday => DateStyle::Full,
+		4.. => DateStyle::Long,
+		3 => DateStyle::Medium,
+		_ => DateStyle::Short,
+	}
+}
+
+/// Parses a pattern, changing the widths of its names to the requested ones. Numbers are padded
+/// if requested, but written in words only if the pattern does so.
+fn pattern_items(pattern: &'static str, fields: &[Field]) -> Vec<ItemL10n<'static>> {
+	let width = |symbol| fields.iter().find(|field| field.symbol == symbol).map_or(0, |field| field.width);
+	StrftimeItemsL10n::new(pattern)
+		.map(|item| match item {
+			ItemL10n::Chrono(Item::Numeric(Numeric::Month, _)) if width('M') == 2 => Item::Numeric(Numeric::Month, Pad::Zero).into(),
+			ItemL10n::Chrono(Item::Numeric(Numeric::Day, _)) if width('d') == 2 => Item::Numeric(Numeric::Day, Pad::Zero).into(),
+			ItemL10n::Chrono(Item::Numeric(Numeric::Year, _)) if width('y') == 2 => Item::Numeric(Numeric::YearMod100, Pad::Zero).into(),
+			ItemL10n::Chrono(Item::Fixed(Fixed::ShortMonthName | Fixed::LongMonthName)) => match width('M') {
+				3 => Item::Fixed(Fixed::ShortMonthName).into(),
+				4.. => Item::Fixed(Fixed::LongMonthName).into(),
+				_ => item,
+			},
+			ItemL10n::Chrono(Item::Fixed(Fixed::ShortWeekdayName | Fixed::LongWeekdayName)) => match width('E') {
+				1..=3 => Item::Fixed(Fixed::ShortWeekdayName).into(),
+				4.. => Item::Fixed(Fixed::LongWeekdayName).into(),
+				_ => item,
+			},
+			item => item,
+		})
+		.collect()
+}
@@ -0,0 +1,45 @@
+//! The styles of the date and time patterns of each locale, from the shortest (`7/8/01`) to the
+//! most complete ("Sunday, July 8, 2001").
+
+/// The style of the date pattern (`date_formats` in the locale data), e.g. for July 8, 2001 in
+/// English.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DateStyle {
+	/// With the weekday, e.g. "Sunday, July 8, 2001".
+	Full,
+	/// With the name of the month, e.g. "July 8, 2001".
+	Long,
+	/// With the abbreviated name of the month, e.g. "Jul 8, 2001".
+	Medium,
+	/// All numeric, e.g. "7/8/01".
+	Short,
+}
+
+impl DateStyle {
+	/// Returns the index of the style in the lists of the locale data, starting from 0 for full.
+	pub(crate) fn index(self) -> usize {
+		self as usize
+	}
+}
+
+/// The style of the time pattern (`time_formats` in the locale data), e.g. for 00:34:59 in UTC in
+/// English. The full and long styles name the time zone, and are written without it for values
+/// without an offset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeStyle {
+	/// With the name of the time zone (as `%EZ`), e.g. "12:34:59 AM Coordinated Universal Time".
+	Full,
+	/// With the name of the offset (as `%Z`), e.g. "12:34:59 AM UTC".
+	Long,
+	/// With the seconds, e.g. "12:34:59 AM".
+	Medium,
+	/// In hours and minutes, e.g. "12:34 AM".
+	Short,
+}
+
+impl TimeStyle {
+	/// Returns the index of the style in the lists of the locale data, starting from 0 for full.
+	pub(crate) fn index(self) -> usize {
+		self as usize
+	}
+}
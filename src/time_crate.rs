@@ -0,0 +1,45 @@
+//! Formatting of the dates and times of the `time` crate, with the `time` feature. Their fields
+//! are converted to chrono's types, so they are formatted exactly as chrono's are.
+
+use chrono::{FixedOffset, NaiveDate, NaiveTime};
+
+use crate::{items::StrftimeItemsL10n, DelayedFormatL10n, LocaleDate};
+
+fn naive_date(date: ::time::Date) -> Option<NaiveDate> {
+	NaiveDate::from_ymd_opt(date.year(), u8::from(date.month()).into(), date.day().into())
+}
+
+fn naive_time(time: ::time::Time) -> Option<NaiveTime> {
+	NaiveTime::from_hms_nano_opt(time.hour().into(), time.minute().into(), time.second().into(), time.nanosecond())
+}
+
+impl LocaleDate for ::time::Date {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(naive_date(*self), None, StrftimeItemsL10n::new(fmt), locale)
+	}
+}
+
+impl LocaleDate for ::time::Time {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(None, naive_time(*self), StrftimeItemsL10n::new(fmt), locale)
+	}
+}
+
+impl LocaleDate for ::time::PrimitiveDateTime {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		DelayedFormatL10n::new(naive_date(self.date()), naive_time(self.time()), StrftimeItemsL10n::new(fmt), locale)
+	}
+}
+
+/// Formats as a chrono `DateTime<FixedOffset>`, since the offsets of `time` have no zone: `%Z` is
+/// the offset, e.g. "+09:30".
+impl LocaleDate for ::time::OffsetDateTime {
+	fn formatl<'a>(&self, fmt: &'a str, locale: &str) -> DelayedFormatL10n<StrftimeItemsL10n<'a>> {
+		let (date, time) = (naive_date(self.date()), naive_time(self.time()));
+		let items = StrftimeItemsL10n::new(fmt);
+		match FixedOffset::east_opt(self.offset().whole_seconds()) {
+			Some(offset) => DelayedFormatL10n::new_with_offset(date, time, &offset, items, locale),
+			None => DelayedFormatL10n::new(date, time, items, locale),
+		}
+	}
+}
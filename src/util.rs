@@ -6,7 +6,7 @@ use chrono::FixedOffset;
 
 /// Prints an offset from UTC in the format of `+HHMM` or `+HH:MM`.
 /// `Z` instead of `+00[:]00` is allowed when `allow_zulu` is true.
-pub fn write_local_minus_utc(w: &mut fmt::Formatter, off: FixedOffset, allow_zulu: bool, use_colon: bool) -> fmt::Result {
+pub fn write_local_minus_utc(w: &mut dyn fmt::Write, off: FixedOffset, allow_zulu: bool, use_colon: bool) -> fmt::Result {
 	let off = off.local_minus_utc();
 	if !allow_zulu || off != 0 {
 		let (sign, off) = if off < 0 { ('-', -off) } else { ('+', off) };
@@ -28,6 +28,207 @@ pub fn long_month(key: usize, locale: &str) -> &'static str {
 	find_key(key, &LOCALES.long_months, locale).expect("Internal error: missing long months in the C locale")
 }
 
+/// Returns the abbreviated name of a month as written with a day, or the stand-alone one where the
+/// locale has no other.
+pub fn format_short_month(key: usize, locale: &str) -> &'static str {
+	find_key_or(key, &LOCALES.format_short_months, &LOCALES.short_months, locale).expect("Internal error: missing short months in the C locale")
+}
+
+/// Returns the name of a month as written with a day, e.g. "lipca" in Polish, or the stand-alone
+/// one where the locale has no other.
+pub fn format_long_month(key: usize, locale: &str) -> &'static str {
+	find_key_or(key, &LOCALES.format_long_months, &LOCALES.long_months, locale).expect("Internal error: missing long months in the C locale")
+}
+
+pub fn hebrew_short_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hebrew_short_months, locale).expect("Internal error: missing short Hebrew months in the C locale")
+}
+
+pub fn hebrew_long_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hebrew_long_months, locale).expect("Internal error: missing long Hebrew months in the C locale")
+}
+
+pub fn hijri_short_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hijri_short_months, locale).expect("Internal error: missing short Hijri months in the C locale")
+}
+
+pub fn hijri_long_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hijri_long_months, locale).expect("Internal error: missing long Hijri months in the C locale")
+}
+
+pub fn persian_short_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.persian_short_months, locale).expect("Internal error: missing short Persian months in the C locale")
+}
+
+pub fn persian_long_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.persian_long_months, locale).expect("Internal error: missing long Persian months in the C locale")
+}
+
+pub fn chinese_short_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.chinese_short_months, locale).expect("Internal error: missing short Chinese months in the C locale")
+}
+
+pub fn chinese_long_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.chinese_long_months, locale).expect("Internal error: missing long Chinese months in the C locale")
+}
+
+/// Returns the name of a day of a Chinese month, if the locale doesn't write them with digits (e.g. 初一 in Chinese).
+pub fn chinese_day(key: usize, locale: &str) -> Option<&'static str> {
+	find_key(key, &LOCALES.chinese_days, locale).copied()
+}
+
+pub fn chinese_cyclic_year(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.chinese_cyclic_years, locale).expect("Internal error: missing Chinese cyclic years in the C locale")
+}
+
+pub fn chinese_zodiac(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.chinese_zodiacs, locale).expect("Internal error: missing Chinese zodiacs in the C locale")
+}
+
+/// Returns the plural rule of ordinal numbers for a category, e.g. `n % 10 = 2 and n % 100 != 12` for "2nd" in English.
+pub fn ordinal_rule(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.ordinal_rules, locale).expect("Internal error: missing ordinal_rules in the C locale")
+}
+
+pub fn ordinal_day_fmt(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.ordinal_day_fmt, locale).expect("Internal error: missing ordinal_day_fmt in the C locale")
+}
+
+/// Returns the feminine form of the ordinal day, or the only form for languages without one.
+pub fn ordinal_day_fmt_feminine(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.ordinal_day_fmt_feminine, locale)
+		.copied()
+		.unwrap_or_else(|| ordinal_day_fmt(key, locale))
+}
+
+/// Returns the pattern of a date interval by its greatest difference: none, the year, the month or the day.
+pub fn date_interval_fmt(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.date_interval_fmt, locale).expect("Internal error: missing date_interval_fmt in the C locale")
+}
+
+/// Returns the pattern of a time interval by its greatest difference: none, AM/PM, the hour or the minute.
+pub fn time_interval_fmt(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.time_interval_fmt, locale).expect("Internal error: missing time_interval_fmt in the C locale")
+}
+
+/// Returns the date pattern of a style, from full (0) to short (3).
+pub fn date_format(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.date_formats, locale).expect("Internal error: missing date_formats in the C locale")
+}
+
+/// Returns the time pattern of a style, from full (0) to short (3).
+pub fn time_format(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.time_formats, locale).expect("Internal error: missing time_formats in the C locale")
+}
+
+/// Returns the pattern joining a date (`{1}`) to a time (`{0}`), by the style of the date.
+pub fn date_time_format(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.date_time_formats, locale).expect("Internal error: missing date_time_formats in the C locale")
+}
+
+/// Returns the patterns of the locale by their skeleton (e.g. `MMMd` for `%b %-d`), sorted by skeleton.
+pub fn available_formats(locale: &str) -> &'static [(&'static str, &'static str)] {
+	fallbacks(locale)
+		.find_map(|locale| LOCALES.available_formats.get(locale))
+		.expect("Internal error: missing available_formats in the C locale")
+}
+
+/// Returns the standard name of a metazone, e.g. "Central European Standard Time", if the locale has one.
+pub fn zone_standard_name(key: usize, locale: &str) -> Option<&'static str> {
+	find_key(key, &LOCALES.zone_standard_names, locale)
+		.copied()
+		.filter(|name| !name.is_empty())
+}
+
+pub fn zone_daylight_name(key: usize, locale: &str) -> Option<&'static str> {
+	find_key(key, &LOCALES.zone_daylight_names, locale)
+		.copied()
+		.filter(|name| !name.is_empty())
+}
+
+pub fn zone_generic_name(key: usize, locale: &str) -> Option<&'static str> {
+	find_key(key, &LOCALES.zone_generic_names, locale)
+		.copied()
+		.filter(|name| !name.is_empty())
+}
+
+/// Returns the city a zone is named after, e.g. "ロサンゼルス" for `America/Los_Angeles` in
+/// Japanese, if the locale names it differently from its identifier.
+pub fn zone_exemplar_city(id: &str, locale: &str) -> Option<&'static str> {
+	fallbacks(locale).find_map(|locale| {
+		let cities = LOCALES.zone_exemplar_cities.get(locale)?;
+		let index = cities.binary_search_by_key(&id, |&(id, _)| id).ok()?;
+		Some(cities[index].1)
+	})
+}
+
+/// Returns the CLDR metazone of a zone, e.g. `America_Pacific` for `America/Los_Angeles`.
+pub fn metazone(id: &str) -> Option<&'static str> {
+	let index = LOCALES.metazones.binary_search_by_key(&id, |&(id, _)| id).ok()?;
+	Some(LOCALES.metazones[index].1)
+}
+
+/// Returns the pattern of a positive (0) or negative (1) offset in the GMT format, e.g. `+%H:%M`.
+pub fn gmt_offset_fmt(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.gmt_offset_fmt, locale).expect("Internal error: missing gmt_offset_fmt in the C locale")
+}
+
+pub fn short_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.short_eras, locale).expect("Internal error: missing short eras in the C locale")
+}
+
+pub fn narrow_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.narrow_eras, locale).expect("Internal error: missing narrow eras in the C locale")
+}
+
+pub fn hebrew_short_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hebrew_short_eras, locale).expect("Internal error: missing short Hebrew eras in the C locale")
+}
+
+pub fn hebrew_narrow_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hebrew_narrow_eras, locale).expect("Internal error: missing narrow Hebrew eras in the C locale")
+}
+
+pub fn hijri_short_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hijri_short_eras, locale).expect("Internal error: missing short Hijri eras in the C locale")
+}
+
+pub fn hijri_narrow_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.hijri_narrow_eras, locale).expect("Internal error: missing narrow Hijri eras in the C locale")
+}
+
+pub fn persian_short_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.persian_short_eras, locale).expect("Internal error: missing short Persian eras in the C locale")
+}
+
+pub fn persian_narrow_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.persian_narrow_eras, locale).expect("Internal error: missing narrow Persian eras in the C locale")
+}
+
+pub fn buddhist_short_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.buddhist_short_eras, locale).expect("Internal error: missing short Buddhist eras in the C locale")
+}
+
+pub fn buddhist_narrow_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.buddhist_narrow_eras, locale).expect("Internal error: missing narrow Buddhist eras in the C locale")
+}
+
+pub fn roc_short_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.roc_short_eras, locale).expect("Internal error: missing short ROC eras in the C locale")
+}
+
+pub fn roc_narrow_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.roc_narrow_eras, locale).expect("Internal error: missing narrow ROC eras in the C locale")
+}
+
+pub fn japanese_short_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.japanese_short_eras, locale).expect("Internal error: missing short Japanese eras in the C locale")
+}
+
+pub fn japanese_narrow_era(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.japanese_narrow_eras, locale).expect("Internal error: missing narrow Japanese eras in the C locale")
+}
+
 pub fn short_weekday(key: usize, locale: &str) -> &'static str {
 	find_key(key, &LOCALES.short_weekdays, locale).expect("Internal error: missing short weekdays in the C locale")
 }
@@ -36,24 +237,111 @@ pub fn long_weekday(key: usize, locale: &str) -> &'static str {
 	find_key(key, &LOCALES.long_weekdays, locale).expect("Internal error: missing long weekdays in the C locale")
 }
 
+pub fn narrow_month(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.narrow_months, locale).expect("Internal error: missing narrow months in the C locale")
+}
+
+pub fn short_quarter(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.short_quarters, locale).expect("Internal error: missing short quarters in the C locale")
+}
+
+pub fn long_quarter(key: usize, locale: &str) -> &'static str {
+	find_key(key, &LOCALES.long_quarters, locale).expect("Internal error: missing long quarters in the C locale")
+}
+
 pub fn ampm(key: usize, locale: &str) -> &'static str {
 	find_key(key, &LOCALES.ampm, locale).expect("Internal error: missing AM/PM in the C locale")
 }
 
+pub fn d_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.d_fmt, locale).expect("Internal error: missing d_fmt in the C locale")
+}
+
+pub fn t_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.t_fmt, locale).expect("Internal error: missing t_fmt in the C locale")
+}
+
+pub fn d_t_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.d_t_fmt, locale).expect("Internal error: missing d_t_fmt in the C locale")
+}
+
+pub fn t_fmt_ampm(locale: &str) -> &'static str {
+	find_value(&LOCALES.t_fmt_ampm, locale).expect("Internal error: missing t_fmt_ampm in the C locale")
+}
+
+pub fn era_year_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.era_year_fmt, locale).expect("Internal error: missing era_year_fmt in the C locale")
+}
+
+/// Returns the pattern for the first year of the Japanese imperial eras and of Minguo, if the locale
+/// writes it differently (e.g. 元年 in Japanese).
+pub fn first_era_year_fmt(locale: &str) -> Option<&'static str> {
+	find_value(&LOCALES.first_era_year_fmt, locale)
+}
+
+pub fn chinese_leap_month_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.chinese_leap_month_fmt, locale).expect("Internal error: missing chinese_leap_month_fmt in the C locale")
+}
+
+pub fn chinese_year_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.chinese_year_fmt, locale).expect("Internal error: missing chinese_year_fmt in the C locale")
+}
+
+/// Returns the pattern joining a date (`{1}`) to a time or time interval (`{0}`).
+pub fn date_time_interval_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.date_time_interval_fmt, locale).expect("Internal error: missing date_time_interval_fmt in the C locale")
+}
+
+/// Returns the pattern of an interval whose parts can't be shared, from `{0}` to `{1}`.
+pub fn interval_fallback_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.interval_fallback_fmt, locale).expect("Internal error: missing interval_fallback_fmt in the C locale")
+}
+
+/// Returns the GMT format, placing an offset (`{0}`) after e.g. "GMT" or "UTC".
+pub fn gmt_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.gmt_fmt, locale).expect("Internal error: missing gmt_fmt in the C locale")
+}
+
+pub fn gmt_zero_fmt(locale: &str) -> &'static str {
+	find_value(&LOCALES.gmt_zero_fmt, locale).expect("Internal error: missing gmt_zero_fmt in the C locale")
+}
+
+/// Returns the identifier of the numbering system of the locale, e.g. `arabext` in Persian, if it doesn't use ASCII digits.
+pub fn numbering_system(locale: &str) -> Option<&'static str> {
+	find_value(&LOCALES.numbering_system, locale)
+}
+
 pub fn find_key(key: usize, data: &'static HashMap<String, Vec<&'static str>>, locale: &str) -> Option<&'static &'static str> {
-	data.get(locale)
-		.and_then(|res| res.get(key))
-		.or_else(|| {
-			if locale.contains('-') {
-				locale
-					.split('-')
-					.collect::<Vec<&str>>()
-					.first()
-					.cloned()
-					.and_then(|locale| data.get(locale).and_then(|res| res.get(key)))
-			} else {
-				None
-			}
-		})
-		.or_else(|| data.get("C").and_then(|res| res.get(key)))
+	fallbacks(locale).find_map(|locale| data.get(locale).and_then(|res| res.get(key)))
+}
+
+/// Searches `data`, then `or`, in each locale before falling back to the next one, so that e.g.
+/// the stand-alone names of `pl` are preferred to the format ones of the default.
+pub fn find_key_or(
+	key: usize,
+	data: &'static HashMap<String, Vec<&'static str>>,
+	or: &'static HashMap<String, Vec<&'static str>>,
+	locale: &str,
+) -> Option<&'static &'static str> {
+	fallbacks(locale).find_map(|locale| data.get(locale).or_else(|| or.get(locale)).and_then(|res| res.get(key)))
+}
+
+pub fn find_value(data: &'static HashMap<String, &'static str>, locale: &str) -> Option<&'static str> {
+	fallbacks(locale).find_map(|locale| data.get(locale)).copied()
+}
+
+/// Returns the most specific locale with data of its own for `locale`, even if only some (e.g. the
+/// digits of `ar-eg`), following the same fallbacks as [find_key] but without falling back to the
+/// default. English is provided by the `C` locale.
+pub fn available_locale(locale: &str) -> Option<&str> {
+	fallbacks(locale)
+		.take_while(|&locale| locale != "C")
+		.find(|&locale| locale == "en" || LOCALES.names.contains(locale))
+}
+
+/// Returns the locales to search for a translation, from the most to the least specific.
+/// For example `pt-br` gives `pt-br`, `pt` and then the default, `C`.
+pub fn fallbacks(locale: &str) -> impl Iterator<Item = &str> {
+	let language = locale.split('-').next().filter(|_| locale.contains('-'));
+	std::iter::once(locale).chain(language).chain(std::iter::once("C"))
 }
@@ -0,0 +1,126 @@
+//! Names of time zones, by the CLDR metazone (e.g. "Central European Time") the zones of the IANA
+//! database belong to.
+
+use std::{any::Any, borrow::Cow};
+
+use chrono::{FixedOffset, Offset, Utc};
+
+use crate::util;
+
+/// The offset of a time zone, which can tell which zone it is from.
+///
+/// Implemented by chrono's offsets and, with the `chrono-tz` feature, by those of `chrono_tz::Tz`,
+/// whose `DateTime`s [LocaleDate::formatl](crate::LocaleDate::formatl) formats in their zone. The
+/// name of the offset written by `%Z` is its [Display](std::fmt::Display) output, e.g. "ACST".
+pub trait ZoneOffset: Offset + std::fmt::Display {
+	/// Returns the IANA identifier of the time zone, e.g. `Australia/Adelaide`, if known.
+	fn zone_id(&self) -> Option<&str> {
+		None
+	}
+
+	/// Returns whether the offset is the daylight saving time of its zone.
+	fn is_dst(&self) -> bool {
+		false
+	}
+}
+
+impl ZoneOffset for FixedOffset {}
+
+impl ZoneOffset for Utc {
+	fn zone_id(&self) -> Option<&str> {
+		Some("Etc/UTC")
+	}
+}
+
+#[cfg(feature = "chrono-tz")]
+impl ZoneOffset for chrono_tz::TzOffset {
+	fn zone_id(&self) -> Option<&str> {
+		Some(chrono_tz::OffsetName::tz_id(self))
+	}
+
+	fn is_dst(&self) -> bool {
+		!chrono_tz::OffsetComponents::dst_offset(self).is_zero()
+	}
+}
+
+/// Returns the name and value of an offset, e.g. ("ACST", +09:30), and the zone it is from.
+pub(crate) fn describe<O: ZoneOffset>(offset: &O) -> ((String, FixedOffset), Option<Zone>) {
+	((offset.to_string(), offset.fix()), Zone::of(offset))
+}
+
+/// Describes the offset of a `DateTime`, with its zone for the offsets of chrono and, with the
+/// `chrono-tz` feature, of `chrono_tz::Tz`. Other offsets are described as fixed ones.
+pub(crate) fn describe_any<O: Offset + 'static>(offset: &O) -> ((String, FixedOffset), Option<Zone>) {
+	let any: &dyn Any = offset;
+	if let Some(offset) = any.downcast_ref::<Utc>() {
+		return describe(offset);
+	}
+	#[cfg(feature = "chrono-tz")]
+	if let Some(offset) = any.downcast_ref::<chrono_tz::TzOffset>() {
+		return describe(offset);
+	}
+	describe(&offset.fix())
+}
+
+/// The time zone of a date, as told by its offset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Zone {
+	/// The IANA identifier.
+	pub id: String,
+	/// Whether the date is in the daylight saving time of the zone.
+	pub dst: bool,
+}
+
+impl Zone {
+	pub fn of<O: ZoneOffset>(offset: &O) -> Option<Zone> {
+		offset.zone_id().map(|id| Zone {
+			id: id.to_owned(),
+			dst: offset.is_dst(),
+		})
+	}
+
+	/// Returns the specific name of the zone, e.g. "Pacific Daylight Time", if the locale has one.
+	pub fn specific_name(&self, locale: &str) -> Option<&'static str> {
+		let metazone = self.metazone()?;
+		if self.dst {
+			util::zone_daylight_name(metazone, locale)
+		} else {
+			util::zone_standard_name(metazone, locale)
+		}
+	}
+
+	/// Returns the generic name of the zone, e.g. "Pacific Time", or its standard name for zones
+	/// which don't observe daylight saving time, if the locale has one.
+	pub fn generic_name(&self, locale: &str) -> Option<&'static str> {
+		let metazone = self.metazone()?;
+		util::zone_generic_name(metazone, locale).or_else(|| util::zone_standard_name(metazone, locale))
+	}
+
+	/// Returns the city the zone is named after in the locale, e.g. "Los Angeles" for
+	/// `America/Los_Angeles`, or the last part of its identifier if the locale doesn't name it.
+	pub fn exemplar_city(&self, locale: &str) -> Cow<'static, str> {
+		match util::zone_exemplar_city(&self.id, locale) {
+			Some(city) => Cow::Borrowed(city),
+			None => Cow::Owned(self.id.rsplit('/').next().unwrap_or_default().replace('_', " ")),
+		}
+	}
+
+	/// Returns the index of the metazone of the zone in the `zone_*_names` lists, for the metazones
+	/// the locale data names. Only the current metazone of each zone is known, and it's used for
+	/// every date.
+	fn metazone(&self) -> Option<usize> {
+		let metazone = util::metazone(&self.id)?;
+		METAZONES.iter().position(|&named| named == metazone)
+	}
+}
+
+/// The metazones with names in the locale data, in the order of the `zone_*_names` lists. The
+/// metazones of the zones are the ones of CLDR, from `data/metazones.json`.
+#[rustfmt::skip]
+const METAZONES: [&str; 40] = [
+	"UTC", "GMT", "Europe_Western", "Europe_Central", "Europe_Eastern", "Moscow", "Africa_Western", "Africa_Central",
+	"Africa_Eastern", "Africa_Southern", "Arabian", "Gulf", "Iran", "Israel", "Afghanistan", "Pakistan", "India", "Indochina",
+	"Indonesia_Western", "Singapore", "Philippines", "China", "Hong_Kong", "Taipei", "Korea", "Japan", "Australia_Western",
+	"Australia_Central", "Australia_Eastern", "New_Zealand", "Hawaii_Aleutian", "Alaska", "America_Pacific", "America_Mountain",
+	"America_Central", "America_Eastern", "Atlantic", "Newfoundland", "Argentina", "Brasilia",
+];
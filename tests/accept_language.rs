@@ -0,0 +1,23 @@
+use chrono::NaiveDate;
+use chrono_lc::{locale_from_accept_language, LocaleDate};
+
+#[test]
+fn accept_language() {
+	assert_eq!(locale_from_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"), "fr");
+	assert_eq!(locale_from_accept_language("pt-BR,pt;q=0.9"), "pt-br");
+	assert_eq!(locale_from_accept_language("de;q=0.5, it;q=0.8"), "it");
+	assert_eq!(locale_from_accept_language("sv-SE, es-MX;q=0.9, en;q=0.8"), "es");
+	assert_eq!(locale_from_accept_language("en-GB,en;q=0.9,fr;q=0.8"), "en-gb");
+	assert_eq!(locale_from_accept_language("en-US,en;q=0.9"), "en");
+	// locales with only some data of their own, e.g. their digits
+	assert_eq!(locale_from_accept_language("ar-EG, ar;q=0.9"), "ar-eg");
+	assert_eq!(locale_from_accept_language("fr;q=0, de"), "de");
+	assert_eq!(locale_from_accept_language("sv, *;q=0.5, fr;q=0.1"), "C");
+	assert_eq!(locale_from_accept_language("sv, fr;q=invalid"), "C");
+	assert_eq!(locale_from_accept_language(""), "C");
+
+	let date = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+	let locale = locale_from_accept_language("fr-CH, fr;q=0.9, en;q=0.8");
+	assert_eq!(date.formatl("%A %e %B %Y", &locale).to_string(), "dimanche  8 juillet 2001");
+	assert_eq!(date.formatl("%d", &locale_from_accept_language("ar-EG")).to_string(), "٠٨");
+}
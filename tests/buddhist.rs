@@ -0,0 +1,15 @@
+use chrono::NaiveDate;
+use chrono_lc::LocaleDate;
+
+#[test]
+fn format_buddhist_calendar() {
+	let date = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+	assert_eq!(date.formatl("%A %-d %B %Y", "th-u-ca-buddhist").to_string(), "วันอาทิตย์ 8 กรกฎาคม 2544");
+	assert_eq!(date.formatl("%-d %B %EY", "th_TH-u-ca-buddhist").to_string(), "8 กรกฎาคม พ.ศ. 2544");
+	assert_eq!(date.formatl("%d/%m/%Y", "th-u-ca-buddhist").to_string(), "08/07/2544");
+	assert_eq!(date.formatl("%b %e, %EY", "en-u-ca-buddhist").to_string(), "Jul  8, 2544 BE");
+
+	// Thai keeps the Gregorian calendar by default
+	assert_eq!(date.formatl("%-d %B %Y", "th").to_string(), "8 กรกฎาคม 2001");
+	assert_eq!(date.formatl("%EY", "th").to_string(), "ค.ศ. 2001");
+}
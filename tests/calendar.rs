@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use chrono::{Datelike, NaiveDate, TimeZone, Utc};
+use chrono_lc::calendar::{Calendar, CalendarFields, Era};
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// A fiscal year starting in April, named after the year it ends in.
+#[derive(Debug)]
+struct Fiscal;
+
+impl Calendar for Fiscal {
+	fn fields(&self, date: &NaiveDate) -> CalendarFields {
+		let start = |year| ymd(year, 4, 1);
+		let year = if date.month() >= 4 { date.year() + 1 } else { date.year() };
+		CalendarFields {
+			year,
+			month: (date.month() + 8) % 12 + 1,
+			leap_month: false,
+			day: date.day(),
+			ordinal: (*date - start(year - 1)).num_days() as u32 + 1,
+		}
+	}
+
+	fn era(&self, date: &NaiveDate, _locale: &str) -> Era {
+		Era {
+			year: self.fields(date).year,
+			backwards: false,
+			name: "FY".into(),
+			narrow_name: Cow::Borrowed("F"),
+		}
+	}
+
+	fn year_with_era_fmt(&self, _date: &NaiveDate, _locale: &str) -> Cow<'static, str> {
+		"%EC%Ey".into()
+	}
+}
+
+#[test]
+fn format_custom_calendar() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%EY %m/%d %j", "en").with_calendar(Fiscal).to_string(), "FY2002 04/08 099");
+	assert_eq!(
+		ymd(2002, 3, 31).formatl("%EY %m %j", "en").with_calendar(Fiscal).to_string(),
+		"FY2002 12 365"
+	);
+	// names default to the Gregorian ones
+	assert_eq!(
+		date.formatl("%A %e %B %Y", "fr").with_calendar(Fiscal).to_string(),
+		"dimanche  8 juillet 2002"
+	);
+
+	// the calendar replaces the one selected by the locale
+	assert_eq!(date.formatl("%Y", "ja-u-ca-japanese").with_calendar(Fiscal).to_string(), "2002");
+	let dt = Utc.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	assert_eq!(dt.formatl("%Y %H:%M %z", "en").with_calendar(Fiscal).to_string(), "2002 00:34 +0000");
+}
+
+#[derive(Debug)]
+struct Minimal;
+
+impl Calendar for Minimal {
+	fn fields(&self, date: &NaiveDate) -> CalendarFields {
+		CalendarFields {
+			year: date.year() - 2000,
+			..CalendarFields::from(*date)
+		}
+	}
+}
+
+#[test]
+fn format_default_eras() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%EY", "en").with_calendar(Minimal).to_string(), "1 AD");
+	// 元年 is only written by the Japanese and ROC calendars
+	assert_eq!(date.formatl("%EY", "ja").with_calendar(Minimal).to_string(), "西暦1年");
+	assert_eq!(ymd(1999, 1, 1).formatl("%EY %EN", "en").with_calendar(Minimal).to_string(), "2 BC B");
+}
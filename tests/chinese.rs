@@ -0,0 +1,70 @@
+use chrono::NaiveDate;
+use chrono_lc::calendar::chinese::{ChineseDate, Zodiac};
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn fields(date: ChineseDate) -> (i32, u32, bool, u32) {
+	(date.year(), date.month(), date.is_leap_month(), date.day())
+}
+
+#[test]
+fn chinese_from_gregorian() {
+	let date = ChineseDate::from_date(ymd(2001, 7, 8)).unwrap();
+	assert_eq!(fields(date), (2001, 5, false, 18));
+	assert_eq!(date.ordinal(), 166);
+	assert_eq!(date.cyclic_year(), 18);
+	assert_eq!(date.zodiac(), Zodiac::Snake);
+
+	let date = |year, month, day| ChineseDate::from_date(ymd(year, month, day)).map(fields);
+	assert_eq!(date(2001, 1, 23), Some((2000, 12, false, 29)));
+	assert_eq!(date(2001, 1, 24), Some((2001, 1, false, 1)));
+	// 2023 repeated its second month
+	assert_eq!(date(2023, 3, 21), Some((2023, 2, false, 30)));
+	assert_eq!(date(2023, 3, 22), Some((2023, 2, true, 1)));
+	assert_eq!(date(2023, 4, 20), Some((2023, 3, false, 1)));
+	assert_eq!(date(2033, 12, 22), Some((2033, 11, true, 1)));
+
+	// the table covers the years from 1900 to 2100
+	assert_eq!(date(1900, 1, 30), None);
+	assert_eq!(date(1900, 1, 31), Some((1900, 1, false, 1)));
+	assert_eq!(date(2101, 1, 28), Some((2100, 12, false, 29)));
+	assert_eq!(date(2101, 1, 29), None);
+
+	assert_eq!(ChineseDate::from_date(ymd(1984, 2, 2)).unwrap().cyclic_year(), 1);
+	assert_eq!(ChineseDate::from_date(ymd(1984, 2, 1)).unwrap().cyclic_year(), 60);
+	assert_eq!(Zodiac::from_year(1984), Zodiac::Rat);
+	assert_eq!(Zodiac::from_year(2024), Zodiac::Dragon);
+}
+
+#[test]
+fn format_chinese_calendar() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%EY%B%d", "zh-u-ca-chinese").to_string(), "2001辛巳年五月十八");
+	assert_eq!(date.formatl("农历%B%d", "zh_CN-u-ca-chinese").to_string(), "农历五月十八");
+	assert_eq!(date.formatl("%EC%Ez年", "zh-TW-u-ca-chinese").to_string(), "辛巳蛇年");
+	assert_eq!(date.formatl("%EY %B %-d일", "ko-u-ca-chinese").to_string(), "신사년 5월 18일");
+	assert_eq!(date.formatl("%B %-d, %EY", "en-u-ca-chinese").to_string(), "Fifth Month 18, 2001(xin-si)");
+	assert_eq!(date.formatl("%Y-%m-%d %j %Ey", "en-u-ca-chinese").to_string(), "2001-05-18 166 18");
+
+	// leap months
+	let date = ymd(2023, 3, 22);
+	assert_eq!(date.formatl("%B%d", "zh-u-ca-chinese").to_string(), "闰二月初一");
+	assert_eq!(date.formatl("%B%d", "zh-TW-u-ca-chinese").to_string(), "閏二月初一");
+	assert_eq!(date.formatl("%B %-d일", "ko-u-ca-chinese").to_string(), "윤2월 1일");
+	assert_eq!(date.formatl("%b %d", "en-u-ca-chinese").to_string(), "Mo2bis 01");
+
+	// the zodiac follows the Chinese year in any calendar
+	assert_eq!(ymd(2024, 2, 9).formatl("%Y %Ez", "zh").to_string(), "2024 兔");
+	assert_eq!(ymd(2024, 2, 10).formatl("%Y %Ez", "zh").to_string(), "2024 龙");
+	assert_eq!(ymd(2024, 2, 10).formatl("%Ez", "en").to_string(), "Dragon");
+	assert_eq!(ymd(2024, 2, 10).formatl("%Ez", "ja").to_string(), "辰");
+	assert_eq!(ymd(2024, 2, 10).formatl("%Ez", "th").to_string(), "มะโรง");
+	// CLDR doesn't name the animals in Hebrew
+	assert_eq!(ymd(2024, 2, 10).formatl("%Ez", "he").to_string(), "Dragon");
+
+	// dates out of the supported range fall back to the Gregorian calendar
+	assert_eq!(ymd(1899, 7, 8).formatl("%EY %B %d", "zh-u-ca-chinese").to_string(), "公元1899年 七月 08");
+}
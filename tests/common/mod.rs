@@ -0,0 +1,79 @@
+//! The formats and locales which the types of other date and time crates are checked to be
+//! formatted with as chrono's types with the same fields are.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, FixedOffset, TimeZone, Timelike};
+use chrono_lc::LocaleDate;
+
+/// The locales of the per-locale tests, and some with extensions.
+pub const LOCALES: [&str; 29] = [
+	"C",
+	"ar-EG",
+	"ar-SA",
+	"ar",
+	"de",
+	"en-GB",
+	"es",
+	"fa-AF",
+	"fa",
+	"fi",
+	"fil",
+	"fr",
+	"he",
+	"it",
+	"ja",
+	"ko",
+	"nl",
+	"pl",
+	"pt-BR",
+	"pt",
+	"th",
+	"tr",
+	"zh-TW",
+	"zh",
+	"ja-u-ca-japanese",
+	"th-u-ca-buddhist",
+	"fa-u-ca-persian",
+	"zh-u-ca-chinese",
+	"hi-u-nu-deva",
+];
+
+/// The specifiers of the per-locale tests, and those of the locale extensions, with the fields
+/// they need: the date (D), the time (T) and the offset (O).
+pub const FORMATS: [(&str, &str); 14] = [
+	("%Y %C %y %m %b %B %h %d %e %a %A %w %u", "D"),
+	("%U %W %G %g %V %j %D %x %F %v", "D"),
+	("%H %k %I %l %P %p %M %S %f %.f %.3f", "T"),
+	("%R %T %X %r", "T"),
+	("%z %:z %Z", "O"),
+	("%c", "DT"),
+	("%+", "DTO"),
+	("%s", "DT"),
+	("%t %n %% %Eo", "D"),
+	("%EC %Ey %EY %EN", "D"),
+	("%EO %EG", "O"),
+	("%EZ %Ev %EV", "O"),
+	("%q", "D"),
+	("%Ez", "D"),
+];
+
+/// The date the other crates' types are compared with: 2001-07-08 00:34:59.026490708 +09:30.
+pub fn chrono_dt() -> DateTime<FixedOffset> {
+	FixedOffset::east_opt(34200)
+		.unwrap()
+		.with_ymd_and_hms(2001, 7, 8, 0, 34, 59)
+		.unwrap()
+		.with_nanosecond(26_490_708)
+		.unwrap()
+}
+
+/// Formats a value with the fields `fields`, checking that it fails with `fmt::Error` if and only
+/// if the format needs others.
+pub fn format<T: LocaleDate>(value: &T, fields: &str, (fmt, needs): (&str, &str), locale: &str) -> Option<String> {
+	let mut s = String::new();
+	let res = write!(s, "{}", value.formatl(fmt, locale)).ok().map(|_| s);
+	let expected = needs.chars().all(|field| fields.contains(field));
+	assert_eq!(res.is_some(), expected, "{} in {} with {}", fmt, locale, fields);
+	res
+}
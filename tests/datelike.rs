@@ -0,0 +1,245 @@
+use std::fmt;
+
+use chrono::{Datelike, FixedOffset, IsoWeek, NaiveDate, NaiveDateTime, Timelike, Utc, Weekday};
+use chrono_lc::{format_l10n, formatl_datelike, to_naive_date_time, LocaleDate, StrftimeItemsL10n};
+
+/// A timestamp of an application, wrapping chrono's.
+#[derive(Clone, Copy, Debug)]
+struct Timestamp(NaiveDateTime);
+
+impl Datelike for Timestamp {
+	fn year(&self) -> i32 {
+		self.0.year()
+	}
+	fn month(&self) -> u32 {
+		self.0.month()
+	}
+	fn month0(&self) -> u32 {
+		self.0.month0()
+	}
+	fn day(&self) -> u32 {
+		self.0.day()
+	}
+	fn day0(&self) -> u32 {
+		self.0.day0()
+	}
+	fn ordinal(&self) -> u32 {
+		self.0.ordinal()
+	}
+	fn ordinal0(&self) -> u32 {
+		self.0.ordinal0()
+	}
+	fn weekday(&self) -> Weekday {
+		self.0.weekday()
+	}
+	fn iso_week(&self) -> IsoWeek {
+		self.0.iso_week()
+	}
+	fn with_year(&self, year: i32) -> Option<Self> {
+		self.0.with_year(year).map(Timestamp)
+	}
+	fn with_month(&self, month: u32) -> Option<Self> {
+		self.0.with_month(month).map(Timestamp)
+	}
+	fn with_month0(&self, month0: u32) -> Option<Self> {
+		self.0.with_month0(month0).map(Timestamp)
+	}
+	fn with_day(&self, day: u32) -> Option<Self> {
+		self.0.with_day(day).map(Timestamp)
+	}
+	fn with_day0(&self, day0: u32) -> Option<Self> {
+		self.0.with_day0(day0).map(Timestamp)
+	}
+	fn with_ordinal(&self, ordinal: u32) -> Option<Self> {
+		self.0.with_ordinal(ordinal).map(Timestamp)
+	}
+	fn with_ordinal0(&self, ordinal0: u32) -> Option<Self> {
+		self.0.with_ordinal0(ordinal0).map(Timestamp)
+	}
+}
+
+impl Timelike for Timestamp {
+	fn hour(&self) -> u32 {
+		self.0.hour()
+	}
+	fn minute(&self) -> u32 {
+		self.0.minute()
+	}
+	fn second(&self) -> u32 {
+		self.0.second()
+	}
+	fn nanosecond(&self) -> u32 {
+		self.0.nanosecond()
+	}
+	fn with_hour(&self, hour: u32) -> Option<Self> {
+		self.0.with_hour(hour).map(Timestamp)
+	}
+	fn with_minute(&self, min: u32) -> Option<Self> {
+		self.0.with_minute(min).map(Timestamp)
+	}
+	fn with_second(&self, sec: u32) -> Option<Self> {
+		self.0.with_second(sec).map(Timestamp)
+	}
+	fn with_nanosecond(&self, nano: u32) -> Option<Self> {
+		self.0.with_nanosecond(nano).map(Timestamp)
+	}
+}
+
+fn dt() -> NaiveDateTime {
+	NaiveDate::from_ymd_opt(2001, 7, 8)
+		.unwrap()
+		.and_hms_nano_opt(0, 34, 59, 26_490_708)
+		.unwrap()
+}
+
+#[test]
+fn format_wrapper_types() {
+	let ts = Timestamp(dt());
+	assert_eq!(to_naive_date_time(&ts), Some(dt()));
+	assert_eq!(
+		to_naive_date_time(&ts).unwrap().formatl("%A %-d %B %Y %H:%M:%S%.3f", "de").to_string(),
+		"Sonntag 8 Juli 2001 00:34:59.026"
+	);
+
+	// with an offset
+	let offset = FixedOffset::east_opt(34200).unwrap();
+	let format = |fmt| to_naive_date_time(&ts).unwrap().formatl(fmt, "en").with_zone(&offset).to_string();
+	assert_eq!(format("%H:%M %EO"), "00:34 GMT+9:30");
+	assert_eq!(format("%z"), "+0930");
+	assert_eq!(
+		to_naive_date_time(&ts).unwrap().formatl("%H:%M %EZ", "fr").with_zone(&Utc).to_string(),
+		"00:34 temps universel coordonné"
+	);
+}
+
+#[test]
+fn format_wrapper_types_with_offsets() {
+	let ts = Timestamp(dt());
+	let offset = FixedOffset::east_opt(34200).unwrap();
+	let format = formatl_datelike(&ts, "%A %B %z", "fr", Some(&offset)).unwrap();
+	assert_eq!(format.to_string(), "dimanche juillet +0930");
+	let format = formatl_datelike(&ts, "%A %B %H:%M %Z", "de", Some(&Utc)).unwrap();
+	assert_eq!(format.to_string(), "Sonntag Juli 00:34 UTC");
+	let format = formatl_datelike(&ts, "%A %B", "fr", None::<&FixedOffset>).unwrap();
+	assert_eq!(format.to_string(), "dimanche juillet");
+}
+
+/// Writes a timestamp through `format_l10n`, as the `Display` of an application's type may.
+struct Display(Timestamp);
+
+impl fmt::Display for Display {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		format_l10n(f, Some(&self.0), Some(&self.0), None, StrftimeItemsL10n::new("%A %B %H:%M"), "fr")
+	}
+}
+
+#[test]
+fn format_l10n_wrapper_types() {
+	assert_eq!(Display(Timestamp(dt())).to_string(), "dimanche juillet 00:34");
+}
+
+/// The fields of a date and time, as an application may read them, e.g. from a form.
+#[derive(Clone, Copy, Debug)]
+struct Fields {
+	year: i32,
+	month: u32,
+	day: u32,
+	hour: u32,
+	minute: u32,
+}
+
+impl Datelike for Fields {
+	fn year(&self) -> i32 {
+		self.year
+	}
+	fn month(&self) -> u32 {
+		self.month
+	}
+	fn month0(&self) -> u32 {
+		self.month - 1
+	}
+	fn day(&self) -> u32 {
+		self.day
+	}
+	fn day0(&self) -> u32 {
+		self.day - 1
+	}
+	fn ordinal(&self) -> u32 {
+		unimplemented!()
+	}
+	fn ordinal0(&self) -> u32 {
+		unimplemented!()
+	}
+	fn weekday(&self) -> Weekday {
+		unimplemented!()
+	}
+	fn iso_week(&self) -> IsoWeek {
+		unimplemented!()
+	}
+	fn with_year(&self, year: i32) -> Option<Self> {
+		Some(Fields { year, ..*self })
+	}
+	fn with_month(&self, month: u32) -> Option<Self> {
+		Some(Fields { month, ..*self })
+	}
+	fn with_month0(&self, month0: u32) -> Option<Self> {
+		self.with_month(month0 + 1)
+	}
+	fn with_day(&self, day: u32) -> Option<Self> {
+		Some(Fields { day, ..*self })
+	}
+	fn with_day0(&self, day0: u32) -> Option<Self> {
+		self.with_day(day0 + 1)
+	}
+	fn with_ordinal(&self, _: u32) -> Option<Self> {
+		None
+	}
+	fn with_ordinal0(&self, _: u32) -> Option<Self> {
+		None
+	}
+}
+
+impl Timelike for Fields {
+	fn hour(&self) -> u32 {
+		self.hour
+	}
+	fn minute(&self) -> u32 {
+		self.minute
+	}
+	fn second(&self) -> u32 {
+		0
+	}
+	fn nanosecond(&self) -> u32 {
+		0
+	}
+	fn with_hour(&self, hour: u32) -> Option<Self> {
+		Some(Fields { hour, ..*self })
+	}
+	fn with_minute(&self, minute: u32) -> Option<Self> {
+		Some(Fields { minute, ..*self })
+	}
+	fn with_second(&self, _: u32) -> Option<Self> {
+		None
+	}
+	fn with_nanosecond(&self, _: u32) -> Option<Self> {
+		None
+	}
+}
+
+#[test]
+fn reject_invalid_fields() {
+	let fields = Fields {
+		year: 2001,
+		month: 2,
+		day: 28,
+		hour: 13,
+		minute: 5,
+	};
+	// the weekday is computed from the fields
+	let dt = to_naive_date_time(&fields).unwrap();
+	assert_eq!(dt.formatl("%A %-d %B %-I:%M %p", "en").to_string(), "Wednesday 28 February 1:05 PM");
+	assert_eq!(to_naive_date_time(&fields.with_day(30).unwrap()), None);
+	assert_eq!(to_naive_date_time(&fields.with_month(13).unwrap()), None);
+	assert_eq!(to_naive_date_time(&fields.with_hour(24).unwrap()), None);
+	assert!(formatl_datelike(&fields.with_day(30).unwrap(), "%A", "en", Some(&Utc)).is_none());
+}
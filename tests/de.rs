@@ -17,9 +17,9 @@ fn format_it() {
 	assert_eq!(dt.formatl("%C", locale).to_string(), "20");
 	assert_eq!(dt.formatl("%y", locale).to_string(), "01");
 	assert_eq!(dt.formatl("%m", locale).to_string(), "07");
-	assert_eq!(dt.formatl("%b", locale).to_string(), "Juli");
+	assert_eq!(dt.formatl("%b", locale).to_string(), "Jul");
 	assert_eq!(dt.formatl("%B", locale).to_string(), "Juli");
-	assert_eq!(dt.formatl("%h", locale).to_string(), "Juli");
+	assert_eq!(dt.formatl("%h", locale).to_string(), "Jul");
 	assert_eq!(dt.formatl("%d", locale).to_string(), "08");
 	assert_eq!(dt.formatl("%e", locale).to_string(), " 8");
 	assert_eq!(dt.formatl("%e", locale).to_string(), dt.formatl("%_d", locale).to_string());
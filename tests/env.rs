@@ -0,0 +1,52 @@
+use std::env;
+
+use chrono::NaiveDate;
+use chrono_lc::{locale_from_env, LocaleDate};
+
+fn set_env(lc_all: &str, lc_time: &str, lang: &str, language: &str) {
+	for (name, value) in [("LC_ALL", lc_all), ("LC_TIME", lc_time), ("LANG", lang), ("LANGUAGE", language)] {
+		if value.is_empty() {
+			env::remove_var(name);
+		} else {
+			env::set_var(name, value);
+		}
+	}
+}
+
+// Everything is checked from a single test, as the environment is shared by the whole process
+#[test]
+fn locale_from_environment() {
+	set_env("", "", "", "");
+	assert_eq!(locale_from_env(), "C");
+
+	set_env("", "", "fr_FR.UTF-8@euro", "");
+	assert_eq!(locale_from_env(), "fr");
+
+	set_env("", "", "pt_BR.UTF-8", "");
+	assert_eq!(locale_from_env(), "pt-br");
+
+	set_env("", "de_DE.UTF-8", "fr_FR.UTF-8", "");
+	assert_eq!(locale_from_env(), "de");
+
+	set_env("it_IT", "de_DE.UTF-8", "fr_FR.UTF-8", "");
+	assert_eq!(locale_from_env(), "it");
+
+	set_env("", "", "en_GB.UTF-8", "fr");
+	assert_eq!(locale_from_env(), "en-gb");
+
+	set_env("", "", "en_US.UTF-8", "fr");
+	assert_eq!(locale_from_env(), "en");
+
+	set_env("", "", "sv_SE.UTF-8", "sv:es_MX:fr");
+	assert_eq!(locale_from_env(), "es");
+
+	set_env("", "", "C.UTF-8", "fr");
+	assert_eq!(locale_from_env(), "C");
+
+	set_env("", "", "xx_YY", "");
+	assert_eq!(locale_from_env(), "C");
+
+	set_env("", "de_AT.UTF-8", "", "");
+	let date = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+	assert_eq!(date.formatl_env("%A %e %B %Y").to_string(), "Sonntag  8 Juli 2001");
+}
@@ -0,0 +1,55 @@
+use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono_lc::{LocaleDate, LocalizedFormatter};
+
+#[test]
+fn format_many_dates() {
+	let formatter = LocalizedFormatter::new("%A %-d %B %Y", "fr").unwrap();
+	let dates = (8..=10).map(|day| NaiveDate::from_ymd_opt(2001, 7, day).unwrap());
+	let formatted: Vec<String> = dates.map(|date| formatter.format(&date).to_string()).collect();
+	assert_eq!(formatted, ["dimanche 8 juillet 2001", "lundi 9 juillet 2001", "mardi 10 juillet 2001"]);
+}
+
+#[test]
+fn format_like_formatl() {
+	let dt = FixedOffset::east_opt(34200).unwrap().with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	for (fmt, locale) in [("%c", "de"), ("%x %EO", "fr"), ("%EY", "ja-u-ca-japanese"), ("%-d %B %Y", "fa")] {
+		let formatter = LocalizedFormatter::new(fmt, locale).unwrap();
+		assert_eq!(formatter.format(&dt).to_string(), dt.formatl(fmt, locale).to_string());
+	}
+
+	let utc = Utc.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	let formatter = LocalizedFormatter::new("%H:%M %EZ", "fr").unwrap();
+	assert_eq!(
+		formatter.format(&utc).with_zone(utc.offset()).to_string(),
+		"00:34 temps universel coordonné"
+	);
+}
+
+#[test]
+fn reject_invalid_formats() {
+	assert!(LocalizedFormatter::new("%Q", "en").is_err());
+	assert!(LocalizedFormatter::new("%Y-%", "en").is_err());
+	// chrono's internal items, which formatl can't write
+	assert!(LocalizedFormatter::new("%3f", "en").is_err());
+	assert!(LocalizedFormatter::new("%S%6f", "en").is_err());
+	assert!(LocalizedFormatter::new("%S%.3f", "en").is_ok());
+	assert_eq!(LocalizedFormatter::new("%Q", "en").unwrap_err().to_string(), "invalid format string");
+	// unknown locales are formatted in the default one, as with formatl
+	assert!(LocalizedFormatter::new("%B", "xx").is_ok());
+}
+
+#[test]
+fn share_formatters_between_threads() {
+	fn assert_send_sync<T: Send + Sync>() {}
+	assert_send_sync::<LocalizedFormatter>();
+
+	let formatter = std::sync::Arc::new(LocalizedFormatter::new("%d %B", "es").unwrap());
+	let handles: Vec<_> = (1..=3)
+		.map(|month| {
+			let formatter = formatter.clone();
+			std::thread::spawn(move || formatter.format(&NaiveDate::from_ymd_opt(2001, month, 8).unwrap()).to_string())
+		})
+		.collect();
+	let formatted: Vec<String> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+	assert_eq!(formatted, ["08 enero", "08 febrero", "08 marzo"]);
+}
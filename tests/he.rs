@@ -20,14 +20,14 @@ fn format_he() {
 	assert_eq!(dt.formatl("%C", locale).to_string(), "20");
 	assert_eq!(dt.formatl("%y", locale).to_string(), "01");
 	assert_eq!(dt.formatl("%m", locale).to_string(), "07");
-	assert_eq!(dt.formatl("%b", locale).to_string(), "יול");
+	assert_eq!(dt.formatl("%b", locale).to_string(), "יולי");
 	assert_eq!(dt.formatl("%B", locale).to_string(), "יולי");
-	assert_eq!(dt.formatl("%h", locale).to_string(), "יול");
+	assert_eq!(dt.formatl("%h", locale).to_string(), "יולי");
 	assert_eq!(dt.formatl("%d", locale).to_string(), "08");
 	assert_eq!(dt.formatl("%e", locale).to_string(), " 8");
 	assert_eq!(dt.formatl("%e", locale).to_string(), dt.formatl("%_d", locale).to_string());
-	assert_eq!(dt.formatl("%a", locale).to_string(), "ראש");
-	assert_eq!(dt.formatl("%A", locale).to_string(), "ראשון");
+	assert_eq!(dt.formatl("%a", locale).to_string(), "יום א׳");
+	assert_eq!(dt.formatl("%A", locale).to_string(), "יום ראשון");
 	assert_eq!(dt.formatl("%w", locale).to_string(), "0");
 	assert_eq!(dt.formatl("%u", locale).to_string(), "7");
 	assert_eq!(dt.formatl("%U", locale).to_string(), "28");
@@ -39,7 +39,7 @@ fn format_he() {
 	assert_eq!(dt.formatl("%D", locale).to_string(), "07/08/01");
 	assert_eq!(dt.formatl("%x", locale).to_string(), "07/08/01");
 	assert_eq!(dt.formatl("%F", locale).to_string(), "2001-07-08");
-	assert_eq!(dt.formatl("%v", locale).to_string(), " 8-יול-2001");
+	assert_eq!(dt.formatl("%v", locale).to_string(), " 8-יולי-2001");
 
 	// time specifiers
 	assert_eq!(dt.formatl("%H", locale).to_string(), "00");
@@ -48,8 +48,8 @@ fn format_he() {
 	assert_eq!(dt.formatl("%I", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), dt.formatl("%_I", locale).to_string());
-	assert_eq!(dt.formatl("%P", locale).to_string(), "am");
-	assert_eq!(dt.formatl("%p", locale).to_string(), "AM");
+	assert_eq!(dt.formatl("%P", locale).to_string(), "לפנה״צ");
+	assert_eq!(dt.formatl("%p", locale).to_string(), "לפנה״צ");
 	assert_eq!(dt.formatl("%M", locale).to_string(), "34");
 	assert_eq!(dt.formatl("%S", locale).to_string(), "60");
 	assert_eq!(dt.formatl("%f", locale).to_string(), "026490708");
@@ -65,7 +65,7 @@ fn format_he() {
 	assert_eq!(dt.formatl("%R", locale).to_string(), "00:34");
 	assert_eq!(dt.formatl("%T", locale).to_string(), "00:34:60");
 	assert_eq!(dt.formatl("%X", locale).to_string(), "00:34:60");
-	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 AM");
+	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 לפנה״צ");
 
 	// time zone specifiers
 	//assert_eq!(dt.formatl("%Z", locale).to_string(), "ACST");
@@ -73,7 +73,7 @@ fn format_he() {
 	assert_eq!(dt.formatl("%:z", locale).to_string(), "+09:30");
 
 	// date & time specifiers
-	assert_eq!(dt.formatl("%c", locale).to_string(), "ראש יול  8 00:34:60 2001");
+	assert_eq!(dt.formatl("%c", locale).to_string(), "יום א׳ יולי  8 00:34:60 2001");
 	assert_eq!(dt.formatl("%+", locale).to_string(), "2001-07-08T00:34:60.026490708+09:30");
 	assert_eq!(
 		dt.with_nanosecond(1_026_490_000).unwrap().formatl("%+", locale).to_string(),
@@ -103,14 +103,14 @@ fn format_he_naive() {
 	assert_eq!(dt.formatl("%C", locale).to_string(), "20");
 	assert_eq!(dt.formatl("%y", locale).to_string(), "01");
 	assert_eq!(dt.formatl("%m", locale).to_string(), "07");
-	assert_eq!(dt.formatl("%b", locale).to_string(), "יול");
+	assert_eq!(dt.formatl("%b", locale).to_string(), "יולי");
 	assert_eq!(dt.formatl("%B", locale).to_string(), "יולי");
-	assert_eq!(dt.formatl("%h", locale).to_string(), "יול");
+	assert_eq!(dt.formatl("%h", locale).to_string(), "יולי");
 	assert_eq!(dt.formatl("%d", locale).to_string(), "08");
 	assert_eq!(dt.formatl("%e", locale).to_string(), " 8");
 	assert_eq!(dt.formatl("%e", locale).to_string(), dt.formatl("%_d", locale).to_string());
-	assert_eq!(dt.formatl("%a", locale).to_string(), "ראש");
-	assert_eq!(dt.formatl("%A", locale).to_string(), "ראשון");
+	assert_eq!(dt.formatl("%a", locale).to_string(), "יום א׳");
+	assert_eq!(dt.formatl("%A", locale).to_string(), "יום ראשון");
 	assert_eq!(dt.formatl("%w", locale).to_string(), "0");
 	assert_eq!(dt.formatl("%u", locale).to_string(), "7");
 	assert_eq!(dt.formatl("%U", locale).to_string(), "28");
@@ -122,7 +122,7 @@ fn format_he_naive() {
 	assert_eq!(dt.formatl("%D", locale).to_string(), "07/08/01");
 	assert_eq!(dt.formatl("%x", locale).to_string(), "07/08/01");
 	assert_eq!(dt.formatl("%F", locale).to_string(), "2001-07-08");
-	assert_eq!(dt.formatl("%v", locale).to_string(), " 8-יול-2001");
+	assert_eq!(dt.formatl("%v", locale).to_string(), " 8-יולי-2001");
 
 	// time specifiers
 	assert_eq!(dt.formatl("%H", locale).to_string(), "00");
@@ -131,17 +131,17 @@ fn format_he_naive() {
 	assert_eq!(dt.formatl("%I", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), dt.formatl("%_I", locale).to_string());
-	assert_eq!(dt.formatl("%P", locale).to_string(), "am");
-	assert_eq!(dt.formatl("%p", locale).to_string(), "AM");
+	assert_eq!(dt.formatl("%P", locale).to_string(), "לפנה״צ");
+	assert_eq!(dt.formatl("%p", locale).to_string(), "לפנה״צ");
 	assert_eq!(dt.formatl("%M", locale).to_string(), "34");
 	assert_eq!(dt.formatl("%S", locale).to_string(), "60");
 	assert_eq!(dt.formatl("%R", locale).to_string(), "00:34");
 	assert_eq!(dt.formatl("%T", locale).to_string(), "00:34:60");
 	assert_eq!(dt.formatl("%X", locale).to_string(), "00:34:60");
-	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 AM");
+	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 לפנה״צ");
 
 	// date & time specifiers
-	assert_eq!(dt.formatl("%c", locale).to_string(), "ראש יול  8 00:34:60 2001");
+	assert_eq!(dt.formatl("%c", locale).to_string(), "יום א׳ יולי  8 00:34:60 2001");
 	//	assert_eq!(dt.formatl("%+", locale).to_string(), "2001-07-08T00:34:60.026490708+09:30");
 	assert_eq!(dt.formatl("%s", locale).to_string(), "994552499");
 
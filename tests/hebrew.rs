@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+use chrono_lc::calendar::hebrew::{self, HebrewDate};
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn hebrew_from_gregorian() {
+	let date = HebrewDate::from(ymd(2001, 7, 8));
+	assert_eq!((date.year(), date.month(), date.day()), (5761, 10, 17));
+	assert!(!date.is_leap_year());
+
+	let date = HebrewDate::from(ymd(2000, 9, 30));
+	assert_eq!((date.year(), date.month(), date.day(), date.ordinal()), (5761, 1, 1, 1));
+	let date = HebrewDate::from(ymd(2000, 9, 29));
+	assert_eq!((date.year(), date.month(), date.day()), (5760, 13, 29));
+
+	// Purim in a common year and in a leap year, which is in Adar II
+	let date = HebrewDate::from(ymd(2023, 3, 7));
+	assert_eq!((date.year(), date.month(), date.day()), (5783, 6, 14));
+	let date = HebrewDate::from(ymd(2024, 3, 24));
+	assert_eq!((date.year(), date.month(), date.day()), (5784, 7, 14));
+	assert!(date.is_leap_year());
+	let date = HebrewDate::from(ymd(2024, 2, 23));
+	assert_eq!((date.year(), date.month(), date.day()), (5784, 6, 14));
+
+	let date = HebrewDate::from(ymd(2026, 9, 28));
+	assert_eq!((date.year(), date.month(), date.day()), (5787, 1, 17));
+}
+
+#[test]
+fn hebrew_numerals() {
+	assert_eq!(hebrew::numeral(5), "ה׳");
+	assert_eq!(hebrew::numeral(15), "ט״ו");
+	assert_eq!(hebrew::numeral(16), "ט״ז");
+	assert_eq!(hebrew::numeral(17), "י״ז");
+	assert_eq!(hebrew::numeral(30), "ל׳");
+	assert_eq!(hebrew::numeral(770), "תש״ע");
+	assert_eq!(hebrew::numeral(787), "תשפ״ז");
+	assert_eq!(hebrew::numeral(900), "תת״ק");
+}
+
+#[test]
+fn format_hebrew_calendar() {
+	let date = ymd(2026, 9, 28);
+	assert_eq!(date.formatl("%d %B %Y", "he-u-ca-hebrew").to_string(), "י״ז תשרי תשפ״ז");
+	assert_eq!(date.formatl("%A %d %B %Y", "he_IL-u-ca-hebrew").to_string(), "יום שני י״ז תשרי תשפ״ז");
+	assert_eq!(date.formatl("%e %B %Y", "en-u-ca-hebrew").to_string(), "17 Tishri 5787");
+	assert_eq!(date.formatl("%Y-%m-%d %j", "en-u-ca-hebrew").to_string(), "5787-01-17 017");
+	assert_eq!(date.formatl("%EY", "en-u-ca-hebrew").to_string(), "5787 AM");
+	// the Gregorian calendar is still the default
+	assert_eq!(date.formatl("%d %B %Y", "he").to_string(), "28 ספטמבר 2026");
+	assert_eq!(date.formatl("%d %B %Y", "he-u-ca-gregory").to_string(), "28 ספטמבר 2026");
+
+	assert_eq!(ymd(2023, 3, 7).formatl("%e %B %Y", "en-u-ca-hebrew").to_string(), "14 Adar 5783");
+	assert_eq!(ymd(2024, 2, 23).formatl("%e %B %Y", "en-u-ca-hebrew").to_string(), "14 Adar I 5784");
+	assert_eq!(ymd(2024, 3, 24).formatl("%e %B %Y", "en-u-ca-hebrew").to_string(), "14 Adar II 5784");
+	assert_eq!(ymd(2024, 3, 24).formatl("%d %B %Y", "he-u-ca-hebrew").to_string(), "י״ד אדר ב׳ תשפ״ד");
+}
+
+#[test]
+fn format_hebrew_thousands() {
+	// 5000 has no hundreds, tens or units, so it's written with its thousands
+	let date = ymd(1240, 3, 1);
+	assert_eq!(date.formatl("%Y", "en-u-ca-hebrew").to_string(), "5000");
+	assert_eq!(date.formatl("%Y", "he-u-ca-hebrew").to_string(), "ה׳");
+	assert_eq!(ymd(1241, 3, 1).formatl("%Y", "he-u-ca-hebrew").to_string(), "א׳");
+	// years before the creation are written with digits
+	let date = ymd(-3760, 3, 1);
+	assert_eq!(date.formatl("%Y", "en-u-ca-hebrew").to_string(), "0000");
+	assert_eq!(date.formatl("%Y", "he-u-ca-hebrew").to_string(), "0000");
+	assert_eq!(ymd(-3800, 3, 1).formatl("%Y", "he-u-ca-hebrew").to_string(), "-0040");
+}
@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+use chrono_lc::calendar::hijri::HijriDate;
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn fields(date: HijriDate) -> (i32, u32, u32) {
+	(date.year(), date.month(), date.day())
+}
+
+#[test]
+fn hijri_from_gregorian() {
+	assert_eq!(fields(HijriDate::civil(ymd(622, 7, 19))), (1, 1, 1));
+	assert_eq!(fields(HijriDate::civil(ymd(622, 7, 18))), (0, 12, 29));
+	assert_eq!(fields(HijriDate::civil(ymd(2001, 7, 8))), (1422, 4, 16));
+	assert_eq!(fields(HijriDate::civil(ymd(2024, 3, 11))), (1445, 9, 1));
+	assert_eq!(HijriDate::civil(ymd(2024, 3, 11)).ordinal(), 237);
+
+	assert_eq!(fields(HijriDate::umm_al_qura(ymd(2001, 7, 8))), (1422, 4, 17));
+	assert_eq!(fields(HijriDate::umm_al_qura(ymd(2024, 3, 10))), (1445, 8, 29));
+	assert_eq!(fields(HijriDate::umm_al_qura(ymd(2024, 3, 11))), (1445, 9, 1));
+	assert_eq!(fields(HijriDate::umm_al_qura(ymd(2077, 11, 16))), (1500, 12, 30));
+	// the table covers 1300 to 1600 AH, the civil calendar is used for other years
+	assert_eq!(fields(HijriDate::umm_al_qura(ymd(1882, 11, 12))), (1300, 1, 1));
+	assert_eq!(HijriDate::umm_al_qura(ymd(1882, 11, 11)), HijriDate::civil(ymd(1882, 11, 11)));
+	assert_eq!(HijriDate::umm_al_qura(ymd(2180, 1, 1)), HijriDate::civil(ymd(2180, 1, 1)));
+}
+
+#[test]
+fn format_hijri_calendar() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%d %B %Y", "ar-u-ca-islamic-umalqura").to_string(), "17 ربيع الآخر 1422");
+	assert_eq!(
+		date.formatl("%A %d %B %Y %EC", "ar_SA-u-ca-islamic-umalqura").to_string(),
+		"الأحد ١٧ ربيع الآخر ١٤٢٢ هـ"
+	);
+	assert_eq!(date.formatl("%e %B %Y", "en-u-ca-islamic-civil").to_string(), "16 Rabiʻ II 1422");
+	assert_eq!(date.formatl("%b %e, %EY", "en-u-ca-islamic-umalqura").to_string(), "Rab. II 17, 1422 AH");
+	assert_eq!(date.formatl("%Y/%m/%d %j", "en-u-ca-islamic-umalqura").to_string(), "1422/04/17 105");
+	assert_eq!(date.formatl("%d %B %Y", "fa-u-ca-islamic-umalqura").to_string(), "۱۷ ربیع‌الثانی ۱۴۲۲");
+	// locales without Hijri months write them in English
+	assert_eq!(date.formatl("%B", "ja-u-ca-islamic-umalqura").to_string(), "Rabiʻ II");
+
+	// Gregorian dates next to Hijri ones
+	assert_eq!(date.formatl("%d %B %Y", "ar").to_string(), "08 يوليو 2001");
+	// unsupported calendars fall back to the Gregorian one
+	assert_eq!(date.formatl("%d %B %Y", "ar-u-ca-islamic").to_string(), "08 يوليو 2001");
+}
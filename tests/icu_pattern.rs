@@ -0,0 +1,96 @@
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use chrono_lc::LocaleDate;
+
+fn dt() -> NaiveDateTime {
+	NaiveDate::from_ymd_opt(2001, 7, 8)
+		.unwrap()
+		.and_hms_nano_opt(0, 34, 59, 26_490_708)
+		.unwrap()
+}
+
+#[test]
+fn format_icu_patterns() {
+	let format = |pattern, locale| dt().formatl_pattern(pattern, locale).to_string();
+	assert_eq!(format("EEEE d MMMM y", "fr"), "dimanche 8 juillet 2001");
+	assert_eq!(format("EEE, MMM d, yy", "en"), "Sun, Jul 8, 01");
+	assert_eq!(format("dd.MM.yyyy", "de"), "08.07.2001");
+	assert_eq!(format("HH:mm:ss", "en"), "00:34:59");
+	assert_eq!(format("h:mm a", "en"), "12:34 AM");
+	assert_eq!(format("d MMMM y", "fa"), "۸ ژوئیهٔ ۲۰۰۱");
+	// the year of the era, in the locale's calendar
+	assert_eq!(format("Gy年M月d日", "ja-u-ca-japanese"), "平成13年7月8日");
+}
+
+#[test]
+fn quote_icu_literals() {
+	let format = |pattern| dt().formatl_pattern(pattern, "en").to_string();
+	assert_eq!(format("h 'o''clock' a"), "12 o'clock AM");
+	assert_eq!(format("''yy"), "'01");
+	assert_eq!(format("'Week' w"), "Week 27");
+	// a quote is never left open
+	assert!(std::panic::catch_unwind(|| format("h 'o")).is_err());
+}
+
+#[test]
+fn format_icu_fields() {
+	let format = |pattern, locale| dt().formatl_pattern(pattern, locale).to_string();
+	assert_eq!(format("Q QQ QQQ QQQQ", "en"), "3 03 Q3 3rd quarter");
+	assert_eq!(format("QQQ", "fr"), "T3");
+	assert_eq!(format("K k KK kk", "en"), "0 24 00 24");
+	assert_eq!(format("s.S s.SSS s.SSSS", "en"), "59.0 59.026 59.0264");
+	assert_eq!(format("D e c", "en"), "189 7 7");
+
+	let later = dt().with_hour(13).unwrap();
+	assert_eq!(later.formatl_pattern("h K H k", "en").to_string(), "1 1 13 13");
+}
+
+#[test]
+fn pad_icu_fields() {
+	let format = |pattern, locale| dt().formatl_pattern(pattern, locale).to_string();
+	assert_eq!(format("QQ", "hi-u-nu-deva"), "०३");
+	assert_eq!(format("D DD DDD", "en"), "189 189 189");
+	let january = NaiveDate::from_ymd_opt(801, 1, 8).unwrap().and_hms_opt(0, 0, 0).unwrap();
+	let format = |pattern, locale| january.formatl_pattern(pattern, locale).to_string();
+	assert_eq!(format("D DD DDD", "en"), "8 08 008");
+	assert_eq!(format("y yy yyy yyyy", "en"), "801 01 801 0801");
+	assert_eq!(format("DD yyyy", "hi-u-nu-deva"), "०८ ०८०१");
+}
+
+#[test]
+fn format_narrow_months() {
+	let format = |pattern, locale| dt().formatl_pattern(pattern, locale).to_string();
+	assert_eq!(format("MMMMM LLLLL", "en"), "J J");
+	assert_eq!(format("MMMMM", "it"), "L");
+	assert_eq!(format("MMMMM", "ja"), "7");
+	// the locale data has no narrow names of the months of other calendars
+	assert_eq!(format("MMMMM", "fa-u-ca-persian"), format("MMM", "fa-u-ca-persian"));
+}
+
+#[test]
+fn format_icu_zones() {
+	let utc = Utc.from_utc_datetime(&dt());
+	assert_eq!(utc.formatl_pattern("HH:mm z", "en").with_zone(utc.offset()).to_string(), "00:34 UTC");
+	assert_eq!(
+		utc.formatl_pattern("HH:mm zzzz", "fr").with_zone(utc.offset()).to_string(),
+		"00:34 temps universel coordonné"
+	);
+	assert_eq!(utc.formatl_pattern("O X", "en").to_string(), "GMT Z");
+
+	let dt = FixedOffset::east_opt(34200).unwrap().from_local_datetime(&dt()).unwrap();
+	let format = |pattern| dt.formatl_pattern(pattern, "en").to_string();
+	assert_eq!(format("O"), "GMT+9:30");
+	assert_eq!(format("OOOO"), "GMT+09:30");
+	assert_eq!(format("Z"), "+0930");
+	assert_eq!(format("ZZZZZ XXX xxx"), "+09:30 +09:30 +09:30");
+}
+
+#[test]
+fn reject_unknown_icu_fields() {
+	let date = dt().date();
+	let format = |pattern| std::panic::catch_unwind(|| date.formatl_pattern(pattern, "en").to_string());
+	assert!(format("yyyy-MM-dd").is_ok());
+	assert!(format("b").is_err());
+	assert!(format("MMMMMM").is_err());
+	// times aren't in dates
+	assert!(format("HH").is_err());
+}
@@ -0,0 +1,152 @@
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_lc::{IntervalStyle, LocaleInterval};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn ymd_hm(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+	ymd(year, month, day).and_hms_opt(hour, minute, 0).unwrap()
+}
+
+#[test]
+fn format_date_intervals() {
+	let dates = |end, locale| ymd(2001, 7, 8).formatl_interval(&end, IntervalStyle::Date, locale).to_string();
+	assert_eq!(dates(ymd(2001, 7, 10), "en-GB"), "8–10 July 2001");
+	assert_eq!(dates(ymd(2001, 8, 2), "en-GB"), "8 July\u{2009}–\u{2009}2 August 2001");
+	assert_eq!(dates(ymd(2002, 8, 10), "en-GB"), "8 July 2001\u{2009}–\u{2009}10 August 2002");
+	assert_eq!(dates(ymd(2001, 7, 8), "en-GB"), "8 July 2001");
+
+	assert_eq!(dates(ymd(2001, 7, 10), "en"), "July 8 – 10, 2001");
+	assert_eq!(dates(ymd(2001, 8, 2), "en"), "July 8 – August 2, 2001");
+	assert_eq!(dates(ymd(2001, 7, 10), "fr"), "8–10 juillet 2001");
+	assert_eq!(dates(ymd(2001, 7, 10), "de"), "8.–10. Juli 2001");
+	assert_eq!(dates(ymd(2001, 8, 2), "es"), "8 de julio\u{2009}–\u{2009}2 de agosto de 2001");
+	assert_eq!(dates(ymd(2001, 7, 10), "pt-BR"), "8\u{2009}–\u{2009}10 de julho de 2001");
+	assert_eq!(dates(ymd(2001, 8, 2), "ja"), "2001年7月8日～8月2日");
+	assert_eq!(dates(ymd(2001, 7, 10), "ko"), "2001년 7월 8일~10일");
+	assert_eq!(dates(ymd(2001, 7, 10), "fa"), "۸ تا ۱۰ ژوئیهٔ ۲۰۰۱");
+	assert_eq!(dates(ymd(2001, 7, 10), "he"), "8–10 ביולי 2001");
+	assert_eq!(dates(ymd(2001, 8, 2), "he"), "8 יולי – 2 אוגוסט 2001");
+	assert_eq!(dates(ymd(2002, 8, 10), "he"), "8 יולי 2001 – 10 אוגוסט 2002");
+	// months are inflected as they are with a day
+	assert_eq!(dates(ymd(2001, 7, 10), "pl"), "8–10 lipca 2001");
+	assert_eq!(dates(ymd(2001, 8, 2), "pl"), "8 lipca\u{2009}–\u{2009}2 sierpnia 2001");
+	assert_eq!(dates(ymd(2002, 8, 10), "pl"), "8 lipca 2001\u{2009}–\u{2009}10 sierpnia 2002");
+	assert_eq!(dates(ymd(2001, 7, 10), "fi"), "8.–10. heinäkuuta 2001");
+	assert_eq!(dates(ymd(2001, 8, 2), "fi"), "8. heinäkuuta\u{2009}–\u{2009}2. elokuuta 2001");
+}
+
+#[test]
+fn format_date_intervals_in_calendars() {
+	// the same Gregorian month, but different months of the Hebrew calendar
+	let interval = ymd(2001, 7, 8).formatl_interval(&ymd(2001, 7, 25), IntervalStyle::Date, "en-GB-u-ca-hebrew");
+	assert_eq!(interval.to_string(), "17 Tamuz\u{2009}–\u{2009}5 Av 5761");
+	let interval = ymd(2001, 7, 8).formatl_interval(&ymd(2001, 7, 10), IntervalStyle::Date, "th-u-ca-buddhist");
+	assert_eq!(interval.to_string(), "8–10 กรกฎาคม 2544");
+}
+
+#[test]
+fn format_time_intervals() {
+	let times = |start: NaiveDateTime, end, locale| start.formatl_interval(&end, IntervalStyle::Time, locale).to_string();
+	let start = ymd_hm(2001, 7, 8, 0, 34);
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 1, 30), "en"), "12:34 – 1:30 AM");
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 13, 30), "en"), "12:34 AM – 1:30 PM");
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 0, 34), "en"), "12:34 AM");
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 1, 30), "en-GB"), "00:34–01:30");
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 1, 30), "zh"), "00:34–01:30");
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 1, 30), "de"), "00:34–01:30 Uhr");
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 1, 30), "fi"), "0.34–1.30");
+	// 12-hour clocks share AM/PM when they can
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 1, 30), "fil"), "12:34–1:30\u{202f}AM");
+	assert_eq!(
+		times(start, ymd_hm(2001, 7, 8, 13, 30), "fil"),
+		"12:34\u{202f}AM\u{2009}–\u{2009}1:30\u{202f}PM"
+	);
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 0, 50), "ko"), "AM 12:34~12:50");
+	assert_eq!(times(start, ymd_hm(2001, 7, 8, 13, 30), "ko"), "AM 12:34 ~ PM 1:30");
+	// ends on different days are written with their dates
+	assert_eq!(
+		times(start, ymd_hm(2001, 7, 9, 0, 34), "en-GB"),
+		"8 July 2001, 00:34\u{2009}–\u{2009}9 July 2001, 00:34"
+	);
+
+	// dates don't have a time
+	let interval = ymd(2001, 7, 8).formatl_interval(&ymd(2001, 7, 10), IntervalStyle::Time, "en");
+	assert!(std::fmt::write(&mut String::new(), format_args!("{}", interval)).is_err());
+}
+
+#[test]
+fn order_interval_ends() {
+	let dates = ymd(2001, 7, 10).formatl_interval(&ymd(2001, 7, 8), IntervalStyle::Date, "en");
+	assert_eq!(dates.to_string(), "July 8 – 10, 2001");
+	let times = ymd_hm(2001, 7, 8, 1, 30).formatl_interval(&ymd_hm(2001, 7, 8, 0, 34), IntervalStyle::Time, "en-GB");
+	assert_eq!(times.to_string(), "00:34–01:30");
+	// the later end is on the next day, which is written with the dates
+	let times = ymd_hm(2001, 7, 9, 0, 34).formatl_interval(&ymd_hm(2001, 7, 8, 23, 0), IntervalStyle::Time, "en-GB");
+	assert_eq!(times.to_string(), "8 July 2001, 23:00\u{2009}–\u{2009}9 July 2001, 00:34");
+
+	let tz = FixedOffset::east_opt(34200).unwrap();
+	let (start, end) = (
+		tz.with_ymd_and_hms(2001, 7, 8, 0, 34, 0).unwrap(),
+		tz.with_ymd_and_hms(2001, 7, 10, 0, 0, 0).unwrap(),
+	);
+	assert_eq!(end.formatl_interval(&start, IntervalStyle::Date, "en-GB").to_string(), "8–10 July 2001");
+}
+
+#[test]
+fn format_date_time_intervals() {
+	let date_times = |end, locale| {
+		ymd_hm(2001, 7, 8, 0, 34)
+			.formatl_interval(&end, IntervalStyle::DateTime, locale)
+			.to_string()
+	};
+	assert_eq!(date_times(ymd_hm(2001, 7, 8, 1, 30), "en-GB"), "8 July 2001, 00:34–01:30");
+	assert_eq!(date_times(ymd_hm(2001, 7, 8, 1, 30), "ja"), "2001年7月8日 0時34分～1時30分");
+	assert_eq!(
+		date_times(ymd_hm(2001, 7, 9, 1, 30), "en-GB"),
+		"8 July 2001, 00:34\u{2009}–\u{2009}9 July 2001, 01:30"
+	);
+	assert_eq!(
+		date_times(ymd_hm(2001, 7, 9, 1, 30), "fr"),
+		"8 juillet 2001, 00:34\u{2009}–\u{2009}9 juillet 2001, 01:30"
+	);
+	assert_eq!(
+		date_times(ymd_hm(2001, 7, 9, 1, 30), "pl"),
+		"8 lipca 2001, 00:34\u{2009}–\u{2009}9 lipca 2001, 01:30"
+	);
+
+	let tz = FixedOffset::east_opt(34200).unwrap();
+	let start = tz.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	let end = tz.with_ymd_and_hms(2001, 7, 8, 1, 30, 0).unwrap();
+	assert_eq!(
+		start.formatl_interval(&end, IntervalStyle::DateTime, "de").to_string(),
+		"8. Juli 2001, 00:34–01:30 Uhr"
+	);
+}
+
+#[test]
+fn format_intervals_with_zones() {
+	let start = Utc.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	let end = Utc.with_ymd_and_hms(2001, 7, 8, 1, 30, 0).unwrap();
+	let interval = start.formatl_interval(&end, IntervalStyle::Time, "fr");
+	assert_eq!(interval.with_pattern("%H:%M–%H:%M %Z").to_string(), "00:34–01:30 UTC");
+
+	let tz = FixedOffset::east_opt(34200).unwrap();
+	let start = tz.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	let end = tz.with_ymd_and_hms(2001, 7, 8, 1, 30, 0).unwrap();
+	let interval = start.formatl_interval(&end, IntervalStyle::Time, "fr");
+	assert_eq!(interval.with_pattern("%H:%M–%H:%M %Z").to_string(), "00:34–01:30 +09:30");
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn format_intervals_in_time_zones() {
+	let tz = chrono_tz::Europe::Paris;
+	let start = tz.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	let end = tz.with_ymd_and_hms(2001, 7, 8, 1, 30, 0).unwrap();
+	let interval = |pattern| start.formatl_interval(&end, IntervalStyle::Time, "fr").with_pattern(pattern).to_string();
+	assert_eq!(interval("%H:%M–%H:%M %Z"), "00:34–01:30 CEST");
+	assert_eq!(interval("%H:%M–%H:%M (%EZ)"), "00:34–01:30 (heure d’été d’Europe centrale)");
+	assert_eq!(interval("%H:%M–%H:%M %Ev"), "00:34–01:30 heure d’Europe centrale");
+}
@@ -0,0 +1,62 @@
+use chrono::NaiveDate;
+use chrono_lc::calendar::japanese::{Era, JapaneseDate};
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn japanese_from_gregorian() {
+	let date = JapaneseDate::from_date(ymd(2001, 7, 8)).unwrap();
+	assert_eq!((date.era(), date.year(), date.month(), date.day()), (Era::Heisei, 13, 7, 8));
+
+	let era = |date| JapaneseDate::from_date(date).map(|date| (date.era(), date.year()));
+	assert_eq!(era(ymd(1868, 9, 7)), None);
+	assert_eq!(era(ymd(1868, 9, 8)), Some((Era::Meiji, 1)));
+	assert_eq!(era(ymd(1912, 7, 29)), Some((Era::Meiji, 45)));
+	assert_eq!(era(ymd(1912, 7, 30)), Some((Era::Taisho, 1)));
+	assert_eq!(era(ymd(1926, 12, 25)), Some((Era::Showa, 1)));
+	assert_eq!(era(ymd(1989, 1, 7)), Some((Era::Showa, 64)));
+	assert_eq!(era(ymd(1989, 1, 8)), Some((Era::Heisei, 1)));
+	assert_eq!(era(ymd(2019, 4, 30)), Some((Era::Heisei, 31)));
+	assert_eq!(era(ymd(2019, 5, 1)), Some((Era::Reiwa, 1)));
+}
+
+#[test]
+fn format_japanese_calendar() {
+	let date = ymd(2001, 7, 8);
+	let locale = "ja-u-ca-japanese";
+	assert_eq!(date.formatl("%EY", locale).to_string(), "平成13年");
+	assert_eq!(date.formatl("%EY%-m月%-d日", "ja_JP-u-ca-japanese").to_string(), "平成13年7月8日");
+	assert_eq!(date.formatl("%EC%Y年%m月%d日", locale).to_string(), "平成13年07月08日");
+	assert_eq!(date.formatl("%EN%Ey.%m.%d", locale).to_string(), "H13.07.08");
+	assert_eq!(date.formatl("%EY", "en-u-ca-japanese").to_string(), "13 Heisei");
+
+	// the first year of an era is gannen
+	assert_eq!(ymd(1989, 1, 7).formatl("%EY", locale).to_string(), "昭和64年");
+	assert_eq!(ymd(1989, 1, 8).formatl("%EY", locale).to_string(), "平成元年");
+	assert_eq!(ymd(2019, 5, 1).formatl("%EY", locale).to_string(), "令和元年");
+	assert_eq!(ymd(2019, 5, 1).formatl("%EN%Ey", locale).to_string(), "R1");
+	assert_eq!(ymd(2019, 5, 1).formatl("%EY", "en-u-ca-japanese").to_string(), "1 Reiwa");
+
+	// dates older than Meiji fall back to the Gregorian eras
+	assert_eq!(ymd(1868, 9, 7).formatl("%EY", locale).to_string(), "西暦1868年");
+	assert_eq!(ymd(1868, 9, 8).formatl("%EY", locale).to_string(), "明治元年");
+	assert_eq!(ymd(1, 7, 8).formatl("%EY", locale).to_string(), "西暦1年");
+}
+
+#[test]
+fn format_gregorian_eras() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%EY", "ja").to_string(), "西暦2001年");
+	// 元年 is only the first year of the imperial eras
+	assert_eq!(ymd(1, 7, 8).formatl("%EY", "ja").to_string(), "西暦1年");
+	assert_eq!(date.formatl("%Y", "ja").to_string(), "2001");
+	assert_eq!(date.formatl("%EY", "en").to_string(), "2001 AD");
+	assert_eq!(date.formatl("%EC %EN %Ey", "en").to_string(), "AD A 2001");
+	assert_eq!(ymd(-43, 3, 15).formatl("%EY", "en").to_string(), "44 BC");
+	assert_eq!(ymd(0, 1, 1).formatl("%EY", "en").to_string(), "1 BC");
+	// `%EY` is not mistaken for an escaped `%`
+	assert_eq!(date.formatl("%%EY", "en").to_string(), "%EY");
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "jiff")]
+
+mod common;
+
+use chrono_lc::LocaleDate;
+use common::{chrono_dt, format, FORMATS, LOCALES};
+use jiff::{civil, tz, Timestamp, Zoned};
+
+#[test]
+fn format_like_chrono() {
+	let chrono_dt = chrono_dt();
+	let dt = civil::date(2001, 7, 8).at(0, 34, 59, 26_490_708);
+	let zoned = dt.to_zoned(tz::TimeZone::fixed(tz::Offset::from_seconds(34200).unwrap())).unwrap();
+
+	for locale in LOCALES {
+		for fmt in FORMATS {
+			assert_eq!(
+				format(&zoned, "DTO", fmt, locale),
+				format(&chrono_dt, "DTO", fmt, locale),
+				"{} in {}",
+				fmt.0,
+				locale
+			);
+			assert_eq!(
+				format(&dt, "DT", fmt, locale),
+				format(&chrono_dt.naive_local(), "DT", fmt, locale),
+				"{} in {}",
+				fmt.0,
+				locale
+			);
+			assert_eq!(
+				format(&dt.date(), "D", fmt, locale),
+				format(&chrono_dt.date_naive(), "D", fmt, locale),
+				"{} in {}",
+				fmt.0,
+				locale
+			);
+			assert_eq!(
+				format(&dt.time(), "T", fmt, locale),
+				format(&chrono_dt.time(), "T", fmt, locale),
+				"{} in {}",
+				fmt.0,
+				locale
+			);
+		}
+	}
+}
+
+#[test]
+fn format_zones_of_the_tz_database() {
+	let summer: Zoned = "2001-07-08T00:34:59[Europe/Paris]".parse().unwrap();
+	assert_eq!(summer.formatl("%H:%M %Z", "fr").to_string(), "00:34 CEST");
+	assert_eq!(summer.formatl("%EZ", "fr").to_string(), "heure d’été d’Europe centrale");
+	assert_eq!(summer.formatl("%Ev (%EV)", "en").to_string(), "Central European Time (Paris)");
+	assert_eq!(summer.formatl("%z %EO", "de").to_string(), "+0200 GMT+2");
+
+	let winter: Zoned = "2001-01-08T00:34:59[America/Los_Angeles]".parse().unwrap();
+	assert_eq!(winter.formatl("%-I:%M %p %Z", "en").to_string(), "12:34 AM PST");
+	assert_eq!(winter.formatl("%EZ", "en").to_string(), "Pacific Standard Time");
+
+	// timestamps are in UTC
+	let timestamp: Timestamp = "2001-07-08T00:34:59Z".parse().unwrap();
+	assert_eq!(
+		timestamp.formatl("%A %-d %B %Y %H:%M %Z", "es").to_string(),
+		"domingo 8 julio 2001 00:34 UTC"
+	);
+	assert_eq!(timestamp.formatl("%EZ", "en").to_string(), "Coordinated Universal Time");
+}
+
+#[test]
+fn format_offsets_beyond_chrono() {
+	// jiff allows offsets of a day or more, which are written as the greatest one chrono has
+	let offset = tz::Offset::from_seconds(25 * 3600).unwrap();
+	let zoned = civil::date(2001, 7, 8).at(0, 34, 59, 0).to_zoned(offset.to_time_zone()).unwrap();
+	assert_eq!(zoned.formatl("%A %B %H:%M", "fr").to_string(), "dimanche juillet 00:34");
+	assert_eq!(zoned.formatl("%z %Z", "fr").to_string(), "+2359 +25");
+}
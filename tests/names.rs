@@ -0,0 +1,45 @@
+use chrono::{Month, Weekday};
+use chrono_lc::{LocaleName, NameWidth};
+
+#[test]
+fn name_weekdays() {
+	assert_eq!(Weekday::Sun.name_l("fr", NameWidth::Long), "dimanche");
+	assert_eq!(Weekday::Sun.name_l("fr", NameWidth::Short), "dim.");
+	assert_eq!(Weekday::Mon.name_l("de_DE", NameWidth::Long), "Montag");
+	assert_eq!(Weekday::Wed.name_l("ja", NameWidth::Short), "水");
+	// unknown locales are in the default one
+	assert_eq!(Weekday::Fri.name_l("xx", NameWidth::Long), "Friday");
+}
+
+#[test]
+fn name_months() {
+	assert_eq!(Month::July.name_l("es", NameWidth::Long), "julio");
+	assert_eq!(Month::July.name_l("pt-BR", NameWidth::Long), "julho");
+	assert_eq!(Month::January.name_l("en", NameWidth::Short), "Jan");
+	// in the Gregorian calendar, whatever the locale's
+	assert_eq!(Month::July.name_l("en-u-ca-hebrew", NameWidth::Long), "July");
+
+	let names: Vec<&str> = (1..=12).map(|n| Month::try_from(n).unwrap().name_l("it", NameWidth::Long)).collect();
+	assert_eq!(names[0], "gennaio");
+	assert_eq!(names[11], "dicembre");
+}
+
+#[test]
+fn name_months_with_days() {
+	use chrono::NaiveDate;
+	use chrono_lc::LocaleDate;
+
+	let date = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+	// months written with a day are in the format context, e.g. the genitive in Polish
+	assert_eq!(date.formatl("%-d %B %Y", "pl").to_string(), "8 lipca 2001");
+	assert_eq!(date.formatl("%Eo %B", "pl").to_string(), "8. lipca");
+	assert_eq!(date.formatl("%-d. %B %Y", "fi").to_string(), "8. heinäkuuta 2001");
+	assert_eq!(date.formatl("%-d. %b", "fi").to_string(), "8. heinäk.");
+	// and stand-alone on their own
+	assert_eq!(date.formatl("%B %Y", "pl").to_string(), "lipiec 2001");
+	assert_eq!(date.formatl("%B", "fi").to_string(), "heinäkuu");
+	assert_eq!(Month::July.name_l("pl", NameWidth::Long), "lipiec");
+	// names which are the same in both contexts are only given stand-alone
+	assert_eq!(date.formatl("%-d %b", "pl").to_string(), "8 lip");
+	assert_eq!(date.formatl("%-d %B %Y", "pl-PL").to_string(), "8 lipca 2001");
+}
@@ -0,0 +1,51 @@
+use chrono::{FixedOffset, NaiveDate, TimeZone, Timelike};
+use chrono_lc::LocaleDate;
+
+#[test]
+fn format_numbering_systems() {
+	let date = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+	let format = |locale| date.formatl("%Y/%m/%d", locale).to_string();
+	assert_eq!(format("en-u-nu-latn"), "2001/07/08");
+	assert_eq!(format("ar-u-nu-arab"), "٢٠٠١/٠٧/٠٨");
+	assert_eq!(format("ur-u-nu-arabext"), "۲۰۰۱/۰۷/۰۸");
+	assert_eq!(format("hi-u-nu-deva"), "२००१/०७/०८");
+	assert_eq!(format("bn-u-nu-beng"), "২০০১/০৭/০৮");
+	assert_eq!(format("th-u-nu-thai"), "๒๐๐๑/๐๗/๐๘");
+	assert_eq!(format("zh-u-nu-hanidec"), "二〇〇一/〇七/〇八");
+	assert_eq!(format("ja-u-nu-fullwide"), "２００１/０７/０８");
+	// unsupported numbering systems are ignored
+	assert_eq!(format("en-u-nu-roman"), "2001/07/08");
+}
+
+#[test]
+fn format_default_numbering_systems() {
+	let date = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap();
+	assert_eq!(date.formatl("%-d %B %Y", "fa").to_string(), "۸ ژوئیهٔ ۲۰۰۱");
+	assert_eq!(date.formatl("%-d %B %Y", "fa-u-nu-latn").to_string(), "8 ژوئیهٔ 2001");
+	assert_eq!(date.formatl("%d %B %Y", "ar-EG").to_string(), "٠٨ يوليو ٢٠٠١");
+	assert_eq!(date.formatl("%d %B %Y", "ar").to_string(), "08 يوليو 2001");
+	assert_eq!(date.formatl("%d %B %Y", "ar-SA-u-ca-islamic-umalqura").to_string(), "١٧ ربيع الآخر ١٤٢٢");
+	assert_eq!(date.formatl("%d %B %Y", "th-u-ca-buddhist-nu-thai").to_string(), "๐๘ กรกฎาคม ๒๕๔๔");
+}
+
+#[test]
+fn format_every_number() {
+	let dt = FixedOffset::east_opt(34200)
+		.unwrap()
+		.with_ymd_and_hms(2001, 7, 8, 0, 34, 59)
+		.unwrap()
+		.with_nanosecond(26_490_708)
+		.unwrap();
+	let locale = "fa";
+	// padding
+	assert_eq!(dt.formatl("%e|%k|%_m|%-d", locale).to_string(), " ۸| ۰| ۷|۸");
+	// offsets and fractional seconds
+	assert_eq!(dt.formatl("%H:%M:%S%.3f %z %:z", locale).to_string(), "۰۰:۳۴:۵۹.۰۲۶ +۰۹۳۰ +۰۹:۳۰");
+	assert_eq!(dt.formatl("%f %s", locale).to_string(), "۰۲۶۴۹۰۷۰۸ ۹۹۴۵۱۸۲۹۹");
+	// patterns of the locale and years of eras
+	assert_eq!(dt.formatl("%x %X", locale).to_string(), "۰۷/۰۸/۰۱ ۰۰:۳۴:۵۹");
+	assert_eq!(dt.formatl("%Ey", "ja-u-ca-japanese-nu-hanidec").to_string(), "一三");
+	// literals are left as they are, and so are interchange formats
+	assert_eq!(dt.formatl("2001 %Y", locale).to_string(), "2001 ۲۰۰۱");
+	assert_eq!(dt.formatl("%+", locale).to_string(), "2001-07-08T00:34:59.026490708+09:30");
+}
@@ -0,0 +1,52 @@
+use chrono::NaiveDate;
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn format_ordinal_days() {
+	let ordinal = |day, locale| ymd(2001, 7, day).formatl("%Eo", locale).to_string();
+	let english: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 24, 31]
+		.into_iter()
+		.map(|day| ordinal(day, "en"))
+		.collect();
+	assert_eq!(
+		english,
+		["1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd", "24th", "31st"]
+	);
+
+	assert_eq!(ordinal(1, "fr"), "1er");
+	assert_eq!(ordinal(2, "fr_CA"), "2");
+	assert_eq!(ordinal(8, "de"), "8.");
+	assert_eq!(ordinal(8, "es"), "8º");
+	assert_eq!(ordinal(1, "pt-BR"), "1º");
+	assert_eq!(ordinal(8, "pt-BR"), "8");
+	assert_eq!(ordinal(8, "ja"), "8日");
+}
+
+#[test]
+fn format_ordinal_dates() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%A, %B %Eo", "en").to_string(), "Sunday, July 8th");
+	assert_eq!(ymd(2001, 7, 1).formatl("%A %Eo %B", "fr").to_string(), "dimanche 1er juillet");
+	assert_eq!(date.formatl("%A, %Eo %B", "de").to_string(), "Sonntag, 8. Juli");
+	// in the calendar and with the digits of the locale
+	assert_eq!(date.formatl("%Eo %B", "en-u-ca-hebrew").to_string(), "17th Tamuz");
+	assert_eq!(date.formatl("%Eo", "en-u-nu-arab").to_string(), "٨th");
+}
+
+#[test]
+fn format_feminine_ordinal_days() {
+	let ordinal = |day, locale| ymd(2001, 7, day).formatl("%Ef", locale).to_string();
+	assert_eq!(ordinal(1, "es"), "1ª");
+	assert_eq!(ordinal(8, "es"), "8ª");
+	assert_eq!(ordinal(1, "it"), "1ª");
+	assert_eq!(ordinal(1, "pt-BR"), "1ª");
+	assert_eq!(ordinal(8, "pt"), "8");
+	assert_eq!(ordinal(1, "fr"), "1re");
+	// the same as `%Eo` in languages without genders
+	assert_eq!(ordinal(1, "en"), "1st");
+	assert_eq!(ordinal(8, "de"), "8.");
+}
@@ -0,0 +1,46 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use chrono_lc::{LocaleDate, OwnedDelayedFormatL10n};
+
+fn dt() -> NaiveDateTime {
+	NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap()
+}
+
+/// Builds the format string, which is dropped before the date is formatted.
+fn format_with_weekday(dt: &NaiveDateTime, weekday: bool, locale: &str) -> OwnedDelayedFormatL10n {
+	let fmt = if weekday { String::from("%A %-d %B") } else { String::from("%-d %B") };
+	dt.formatl_owned(&fmt, locale)
+}
+
+#[test]
+fn outlive_format_strings() {
+	assert_eq!(format_with_weekday(&dt(), true, "fr").to_string(), "dimanche 8 juillet");
+	assert_eq!(format_with_weekday(&dt(), false, "de").to_string(), "8 Juli");
+
+	let fmt = String::from("%c");
+	let owned = dt().formatl(&fmt, "it").into_owned();
+	let expected = dt().formatl(&fmt, "it").to_string();
+	drop(fmt);
+	assert_eq!(owned.to_string(), expected);
+}
+
+#[test]
+fn store_owned_formats() {
+	struct Row {
+		label: &'static str,
+		date: OwnedDelayedFormatL10n,
+	}
+
+	let rows: Vec<Row> = ["en", "es"]
+		.into_iter()
+		.map(|locale| Row {
+			label: locale,
+			date: dt().formatl_owned(&format!("%B {}", "%Y"), locale),
+		})
+		.collect();
+	let formatted: Vec<String> = rows.iter().map(|row| format!("{}: {}", row.label, row.date)).collect();
+	assert_eq!(formatted, ["en: July 2001", "es: julio 2001"]);
+
+	// and send them to other threads
+	let owned = dt().formatl_owned("%Y年%B%-d日", "ja");
+	assert_eq!(std::thread::spawn(move || owned.to_string()).join().unwrap(), "2001年7月8日");
+}
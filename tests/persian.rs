@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+use chrono_lc::calendar::persian::PersianDate;
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn fields(date: PersianDate) -> (i32, u32, u32) {
+	(date.year(), date.month(), date.day())
+}
+
+#[test]
+fn persian_from_gregorian() {
+	assert_eq!(fields(PersianDate::from(ymd(622, 3, 21))), (1, 1, 1));
+	assert_eq!(fields(PersianDate::from(ymd(2001, 7, 8))), (1380, 4, 17));
+	assert_eq!(PersianDate::from(ymd(2001, 7, 8)).ordinal(), 110);
+	assert_eq!(fields(PersianDate::from(ymd(2024, 3, 19))), (1402, 12, 29));
+	assert_eq!(fields(PersianDate::from(ymd(2024, 3, 20))), (1403, 1, 1));
+	assert_eq!(fields(PersianDate::from(ymd(2025, 3, 20))), (1403, 12, 30));
+	assert!(PersianDate::from(ymd(2025, 3, 20)).is_leap_year());
+	assert_eq!(fields(PersianDate::from(ymd(2025, 3, 21))), (1404, 1, 1));
+
+	// 1502 would be a leap year in the 33-year cycle, but the equinox comes a day later
+	assert!(!PersianDate::from(ymd(2124, 3, 19)).is_leap_year());
+	assert_eq!(fields(PersianDate::from(ymd(2124, 3, 19))), (1502, 12, 29));
+	assert_eq!(fields(PersianDate::from(ymd(2124, 3, 20))), (1503, 1, 1));
+	assert_eq!(fields(PersianDate::from(ymd(2125, 3, 20))), (1503, 12, 30));
+	assert_eq!(fields(PersianDate::from(ymd(2125, 3, 21))), (1504, 1, 1));
+}
+
+#[test]
+fn format_persian_calendar() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%-d %B %Y", "fa-u-ca-persian").to_string(), "۱۷ تیر ۱۳۸۰");
+	assert_eq!(date.formatl("%Y/%m/%d", "fa_IR-u-ca-persian").to_string(), "۱۳۸۰/۰۴/۱۷");
+	assert_eq!(date.formatl("%A %-d %B %Y %EC", "fa-u-ca-persian").to_string(), "یکشنبه ۱۷ تیر ۱۳۸۰ ه‍.ش.");
+	assert_eq!(date.formatl("%-d %B %Y", "fa-AF-u-ca-persian").to_string(), "۱۷ سرطان ۱۳۸۰");
+	assert_eq!(date.formatl("%B %e, %EY", "en-u-ca-persian").to_string(), "Tir 17, 1380 AP");
+
+	// Gregorian dates keep the Persian names of the months
+	assert_eq!(date.formatl("%-d %B %Y", "fa").to_string(), "۸ ژوئیهٔ ۲۰۰۱");
+	assert_eq!(date.formatl("%-d %B %Y", "fa-AF").to_string(), "۸ جولای ۲۰۰۱");
+}
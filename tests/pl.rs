@@ -23,7 +23,7 @@ fn format_pl() {
 	assert_eq!(dt.formatl("%d", locale).to_string(), "08");
 	assert_eq!(dt.formatl("%e", locale).to_string(), " 8");
 	assert_eq!(dt.formatl("%e", locale).to_string(), dt.formatl("%_d", locale).to_string());
-	assert_eq!(dt.formatl("%a", locale).to_string(), "niedz");
+	assert_eq!(dt.formatl("%a", locale).to_string(), "niedz.");
 	assert_eq!(dt.formatl("%A", locale).to_string(), "niedziela");
 	assert_eq!(dt.formatl("%w", locale).to_string(), "0");
 	assert_eq!(dt.formatl("%u", locale).to_string(), "7");
@@ -70,7 +70,7 @@ fn format_pl() {
 	assert_eq!(dt.formatl("%:z", locale).to_string(), "+09:30");
 
 	// date & time specifiers
-	assert_eq!(dt.formatl("%c", locale).to_string(), "niedz lip  8 00:34:60 2001");
+	assert_eq!(dt.formatl("%c", locale).to_string(), "niedz. lip  8 00:34:60 2001");
 	assert_eq!(dt.formatl("%+", locale).to_string(), "2001-07-08T00:34:60.026490708+09:30");
 	assert_eq!(
 		dt.with_nanosecond(1_026_490_000).unwrap().formatl("%+", locale).to_string(),
@@ -106,7 +106,7 @@ fn format_pl_naive() {
 	assert_eq!(dt.formatl("%d", locale).to_string(), "08");
 	assert_eq!(dt.formatl("%e", locale).to_string(), " 8");
 	assert_eq!(dt.formatl("%e", locale).to_string(), dt.formatl("%_d", locale).to_string());
-	assert_eq!(dt.formatl("%a", locale).to_string(), "niedz");
+	assert_eq!(dt.formatl("%a", locale).to_string(), "niedz.");
 	assert_eq!(dt.formatl("%A", locale).to_string(), "niedziela");
 	assert_eq!(dt.formatl("%w", locale).to_string(), "0");
 	assert_eq!(dt.formatl("%u", locale).to_string(), "7");
@@ -138,7 +138,7 @@ fn format_pl_naive() {
 	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 AM");
 
 	// date & time specifiers
-	assert_eq!(dt.formatl("%c", locale).to_string(), "niedz lip  8 00:34:60 2001");
+	assert_eq!(dt.formatl("%c", locale).to_string(), "niedz. lip  8 00:34:60 2001");
 	//	assert_eq!(dt.formatl("%+", locale).to_string(), "2001-07-08T00:34:60.026490708+09:30");
 	assert_eq!(dt.formatl("%s", locale).to_string(), "994552499");
 
@@ -0,0 +1,22 @@
+use chrono::NaiveDate;
+use chrono_lc::LocaleDate;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn format_roc_calendar() {
+	let date = ymd(2001, 7, 8);
+	assert_eq!(date.formatl("%EY%-m月%-d日 %A", "zh-TW-u-ca-roc").to_string(), "民國90年7月8日 星期日");
+	assert_eq!(date.formatl("%Y/%m/%d", "zh_TW-u-ca-roc").to_string(), "90/07/08");
+	assert_eq!(date.formatl("%EY", "en-u-ca-roc").to_string(), "90 Minguo");
+
+	assert_eq!(ymd(1912, 1, 1).formatl("%EY", "zh-TW-u-ca-roc").to_string(), "民國元年");
+	assert_eq!(ymd(1911, 12, 31).formatl("%EY", "zh-TW-u-ca-roc").to_string(), "民國前1年");
+	assert_eq!(ymd(1900, 6, 1).formatl("%EY", "zh-TW-u-ca-roc").to_string(), "民國前12年");
+	assert_eq!(ymd(1900, 6, 1).formatl("%EY", "en-u-ca-roc").to_string(), "12 B.R.O.C.");
+
+	assert_eq!(date.formatl("%EY", "zh-TW").to_string(), "西元2001年");
+	assert_eq!(ymd(1, 7, 8).formatl("%EY", "zh-TW").to_string(), "西元1年");
+}
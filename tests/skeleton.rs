@@ -0,0 +1,88 @@
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_lc::LocaleDate;
+
+fn dt() -> NaiveDateTime {
+	NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap()
+}
+
+#[test]
+fn format_skeletons() {
+	let format = |skeleton, locale| dt().formatl_skeleton(skeleton, locale).unwrap().to_string();
+	assert_eq!(format("MMMd", "en"), "Jul 8");
+	assert_eq!(format("MMMd", "fr"), "8 juil.");
+	assert_eq!(format("MMMd", "de"), "8. Juli");
+	assert_eq!(format("MMMd", "ja"), "7月8日");
+	assert_eq!(format("MMMd", "he"), "8 ביולי");
+	assert_eq!(format("yMMM", "en"), "Jul 2001");
+	assert_eq!(format("yMMMMd", "es"), "8 de julio de 2001");
+	assert_eq!(format("MEd", "en-GB"), "Sun, 08/07");
+	assert_eq!(format("Hm", "fi"), "0.34");
+	assert_eq!(format("Hm", "en-GB"), "00:34");
+	assert_eq!(format("Hms", "it"), "00:34:59");
+	assert_eq!(format("hm", "en"), "12:34 AM");
+	// the hour of the locale's clock
+	assert_eq!(format("jm", "en"), "12:34 AM");
+	assert_eq!(format("jm", "de"), "00:34");
+}
+
+#[test]
+fn match_skeleton_clocks() {
+	let format = |skeleton, locale| dt().formatl_skeleton(skeleton, locale).unwrap().to_string();
+	// the requested clock is kept where the locale has patterns on it
+	assert_eq!(format("Hm", "en"), "00:34");
+	assert_eq!(format("hm", "de"), "12:34\u{202f}AM");
+	// hours from 0 to 11 and from 1 to 24 are written on the 12-hour and 24-hour clocks
+	assert_eq!(format("Km", "en"), "12:34 AM");
+	assert_eq!(format("kms", "fr"), "00:34:59");
+	assert_eq!(format("EKm", "en-GB"), "Sun 12:34\u{202f}AM");
+}
+
+#[test]
+fn adapt_skeleton_widths() {
+	let format = |skeleton, locale| dt().formatl_skeleton(skeleton, locale).unwrap().to_string();
+	// names are widened or shortened
+	assert_eq!(format("MMMMEEEEd", "en"), "Sunday, July 8");
+	assert_eq!(format("yMMMMEd", "fr"), "dim. 8 juillet 2001");
+	// numbers are padded, but never written in words where the locale writes numbers
+	assert_eq!(format("MMdd", "en"), "07/08");
+	assert_eq!(format("yyMd", "en"), "7/8/01");
+	assert_eq!(format("MMMMd", "ja"), "7月8日");
+}
+
+#[test]
+fn join_skeleton_dates_and_times() {
+	let format = |skeleton, locale| dt().formatl_skeleton(skeleton, locale).unwrap().to_string();
+	assert_eq!(format("yMMMdjm", "en"), "Jul 8, 2001, 12:34 AM");
+	assert_eq!(format("yMMMMdHm", "de"), "8. Juli 2001, 00:34");
+	assert_eq!(format("MMMdHms", "fr"), "8 juil., 00:34:59");
+
+	// zones are appended
+	let utc = Utc.from_utc_datetime(&dt());
+	assert_eq!(
+		utc.formatl_skeleton("Hmz", "en").unwrap().with_zone(utc.offset()).to_string(),
+		"00:34 UTC"
+	);
+	assert_eq!(
+		utc.formatl_skeleton("Hmzzzz", "fr").unwrap().with_zone(utc.offset()).to_string(),
+		"00:34 temps universel coordonné"
+	);
+}
+
+#[test]
+fn format_skeleton_eras() {
+	let date = dt().date();
+	assert_eq!(date.formatl_skeleton("GyMMMd", "en").unwrap().to_string(), "Jul 8, 2001 AD");
+	assert_eq!(date.formatl_skeleton("GyMMMd", "ja-u-ca-japanese").unwrap().to_string(), "平成13年7月8日");
+}
+
+#[test]
+fn reject_invalid_skeletons() {
+	let date = dt().date();
+	let format = |skeleton| date.formatl_skeleton(skeleton, "en").map(|format| format.to_string());
+	assert_eq!(format("yMMMd").as_deref(), Some("Jul 8, 2001"));
+	assert_eq!(format(""), None);
+	assert_eq!(format("yQQQ"), None);
+	assert_eq!(format("b"), None);
+	// the period of the day alone has no pattern
+	assert_eq!(format("a"), None);
+}
@@ -0,0 +1,93 @@
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_lc::{DateStyle, LocaleDate, TimeStyle};
+
+fn dt() -> NaiveDateTime {
+	NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap()
+}
+
+#[test]
+fn format_date_styles() {
+	let date = dt().date();
+	let styles = |locale| {
+		[DateStyle::Full, DateStyle::Long, DateStyle::Medium, DateStyle::Short].map(|style| date.formatl_date_style(style, locale).to_string())
+	};
+	assert_eq!(styles("en"), ["Sunday, July 8, 2001", "July 8, 2001", "Jul 8, 2001", "7/8/01"]);
+	assert_eq!(styles("en-GB"), ["Sunday, 8 July 2001", "8 July 2001", "8 Jul 2001", "08/07/2001"]);
+	assert_eq!(styles("de"), ["Sonntag, 8. Juli 2001", "8. Juli 2001", "08.07.2001", "08.07.01"]);
+	assert_eq!(styles("ja"), ["2001年7月8日日曜日", "2001年7月8日", "2001/07/08", "2001/07/08"]);
+	// the months of the patterns are inflected as they are with a day
+	assert_eq!(styles("pl"), ["niedziela, 8 lipca 2001", "8 lipca 2001", "8 lip 2001", "8.07.2001"]);
+	assert_eq!(
+		styles("fi"),
+		["sunnuntai 8. heinäkuuta 2001", "8. heinäkuuta 2001", "8.7.2001", "8.7.2001"]
+	);
+	assert_eq!(date.formatl_date_style(DateStyle::Long, "fa").to_string(), "۸ ژوئیهٔ ۲۰۰۱");
+}
+
+#[test]
+fn format_time_styles() {
+	let time = dt();
+	assert_eq!(time.formatl_time_style(TimeStyle::Short, "en").to_string(), "12:34 AM");
+	assert_eq!(time.formatl_time_style(TimeStyle::Medium, "en").to_string(), "12:34:59 AM");
+	assert_eq!(time.formatl_time_style(TimeStyle::Short, "fr").to_string(), "00:34");
+	assert_eq!(time.formatl_time_style(TimeStyle::Short, "fi").to_string(), "0.34");
+
+	// the longer styles name the zone
+	let utc = Utc.from_utc_datetime(&dt());
+	assert_eq!(
+		utc.formatl_time_style(TimeStyle::Long, "en").with_zone(utc.offset()).to_string(),
+		"12:34:59 AM UTC"
+	);
+	assert_eq!(
+		utc.formatl_time_style(TimeStyle::Full, "fr").with_zone(utc.offset()).to_string(),
+		"00:34:59 temps universel coordonné"
+	);
+
+	// and are written without it for values without an offset
+	assert_eq!(time.formatl_time_style(TimeStyle::Long, "en").to_string(), "12:34:59 AM");
+	assert_eq!(time.formatl_time_style(TimeStyle::Full, "fr").to_string(), "00:34:59");
+	assert_eq!(time.time().formatl_time_style(TimeStyle::Full, "ko").to_string(), "AM 12시 34분 59초");
+}
+
+#[test]
+fn format_date_time_styles() {
+	let dt = dt();
+	assert_eq!(
+		dt.formatl_style(DateStyle::Long, TimeStyle::Short, "de").to_string(),
+		"8. Juli 2001, 00:34"
+	);
+	assert_eq!(dt.formatl_style(DateStyle::Short, TimeStyle::Short, "de").to_string(), "08.07.01, 00:34");
+	assert_eq!(
+		dt.formatl_style(DateStyle::Full, TimeStyle::Medium, "en").to_string(),
+		"Sunday, July 8, 2001 at 12:34:59 AM"
+	);
+	assert_eq!(
+		dt.formatl_style(DateStyle::Medium, TimeStyle::Short, "fr").to_string(),
+		"8 juil. 2001, 00:34"
+	);
+	assert_eq!(
+		dt.formatl_style(DateStyle::Long, TimeStyle::Short, "ko").to_string(),
+		"2001년 7월 8일 AM 12:34"
+	);
+	assert_eq!(
+		dt.formatl_style(DateStyle::Full, TimeStyle::Full, "en").to_string(),
+		"Sunday, July 8, 2001 at 12:34:59 AM"
+	);
+	assert_eq!(
+		dt.formatl_style(DateStyle::Full, TimeStyle::Full, "ja").to_string(),
+		"2001年7月8日日曜日 0時34分59秒"
+	);
+	assert_eq!(
+		dt.formatl_style(DateStyle::Long, TimeStyle::Short, "pl").to_string(),
+		"8 lipca 2001 00:34"
+	);
+	assert_eq!(
+		dt.formatl_style(DateStyle::Long, TimeStyle::Short, "fi").to_string(),
+		"8. heinäkuuta 2001 0.34"
+	);
+	// in the calendar selected by the locale
+	assert_eq!(
+		dt.formatl_style(DateStyle::Long, TimeStyle::Short, "th-u-ca-buddhist").to_string(),
+		"8 กรกฎาคม พ.ศ. 2544 00:34"
+	);
+}
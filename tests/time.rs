@@ -0,0 +1,26 @@
+use std::time::{Duration, SystemTime};
+
+use chrono::NaiveTime;
+use chrono_lc::LocaleDate;
+
+#[test]
+fn format_times() {
+	let time = NaiveTime::from_hms_opt(13, 4, 5).unwrap();
+	assert_eq!(time.formatl("%X", "de").to_string(), "13:04:05");
+	assert_eq!(time.formatl("%r", "en").to_string(), "01:04:05 PM");
+	assert_eq!(time.formatl("%-I:%M %p", "ko").to_string(), "1:04 PM");
+	assert_eq!(time.formatl("%H:%M", "fa").to_string(), "۱۳:۰۴");
+	// times have no date
+	assert!(std::panic::catch_unwind(|| time.formatl("%A", "en").to_string()).is_err());
+}
+
+#[test]
+fn format_system_times() {
+	// 2001-07-08 00:34:59 UTC
+	let time = SystemTime::UNIX_EPOCH + Duration::from_secs(994_552_499);
+	assert_eq!(
+		time.formatl("%A %-d %B %Y %H:%M:%S", "fr").to_string(),
+		"dimanche 8 juillet 2001 00:34:59"
+	);
+	assert_eq!(time.formatl("%Z", "en").to_string(), "UTC");
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "time")]
+
+mod common;
+
+use chrono_lc::LocaleDate;
+use common::{chrono_dt, format, FORMATS, LOCALES};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+fn time_dt() -> OffsetDateTime {
+	let date = Date::from_calendar_date(2001, Month::July, 8).unwrap();
+	let time = Time::from_hms_nano(0, 34, 59, 26_490_708).unwrap();
+	PrimitiveDateTime::new(date, time).assume_offset(UtcOffset::from_hms(9, 30, 0).unwrap())
+}
+
+#[test]
+fn format_like_chrono() {
+	let (chrono_dt, time_dt) = (chrono_dt(), time_dt());
+	for locale in LOCALES {
+		for fmt in FORMATS {
+			let expected = format(&chrono_dt, "DTO", fmt, locale);
+			assert_eq!(format(&time_dt, "DTO", fmt, locale), expected, "{} in {}", fmt.0, locale);
+			assert_eq!(
+				format(&time_dt.date(), "D", fmt, locale),
+				format(&chrono_dt.date_naive(), "D", fmt, locale),
+				"{} in {}",
+				fmt.0,
+				locale
+			);
+			assert_eq!(
+				format(&time_dt.time(), "T", fmt, locale),
+				format(&chrono_dt.time(), "T", fmt, locale),
+				"{} in {}",
+				fmt.0,
+				locale
+			);
+			let primitive = PrimitiveDateTime::new(time_dt.date(), time_dt.time());
+			assert_eq!(
+				format(&primitive, "DT", fmt, locale),
+				format(&chrono_dt.naive_local(), "DT", fmt, locale),
+				"{} in {}",
+				fmt.0,
+				locale
+			);
+		}
+	}
+}
+
+#[test]
+fn format_time_types() {
+	let dt = time_dt();
+	assert_eq!(dt.formatl("%A %-d %B %Y %H:%M", "fr").to_string(), "dimanche 8 juillet 2001 00:34");
+	assert_eq!(dt.formatl("%EO %Z", "en").to_string(), "GMT+9:30 +09:30");
+	assert_eq!(dt.date().formatl("%-d. %B %Y", "de").to_string(), "8. Juli 2001");
+	assert_eq!(dt.time().formatl("%r", "en").to_string(), "12:34:59 AM");
+
+	// skeletons and styles work alike
+	assert_eq!(dt.formatl_skeleton("yMMMd", "en").unwrap().to_string(), "Jul 8, 2001");
+	assert_eq!(dt.formatl_pattern("EEEE d MMMM y", "es").to_string(), "domingo 8 julio 2001");
+}
@@ -0,0 +1,131 @@
+use chrono::{FixedOffset, TimeZone, Utc};
+use chrono_lc::{LocaleDate, TimeStyle};
+
+#[test]
+fn format_chrono_zones() {
+	let dt = Utc.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	assert_eq!(dt.formatl("%Z", "en").to_string(), "UTC");
+	assert_eq!(dt.formatl("%EZ", "en").to_string(), "Coordinated Universal Time");
+	assert_eq!(dt.formatl("%Ev", "fr").to_string(), "temps universel coordonné");
+	assert_eq!(dt.formatl_time_style(TimeStyle::Long, "en").to_string(), "12:34:59 AM UTC");
+	assert_eq!(dt.formatl_skeleton("Hmz", "en").unwrap().to_string(), "00:34 UTC");
+
+	// fixed offsets have no zone, which is written in the localized GMT format
+	let dt = FixedOffset::east_opt(34200).unwrap().with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	assert_eq!(dt.formatl("%Z|%EZ|%Ev|%EV", "en").to_string(), "+09:30|GMT+09:30|GMT+09:30|GMT+09:30");
+	let dt = FixedOffset::west_opt(10800).unwrap().with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	assert_eq!(dt.formatl("%EZ|%Ev", "fa").to_string(), "\u{200e}−۰۳:۰۰ گرینویچ|\u{200e}−۰۳:۰۰ گرینویچ");
+	assert_eq!(dt.formatl("%EZ|%Ev", "ar-EG").to_string(), "غرينتش-٠٣:٠٠|غرينتش-٠٣:٠٠");
+	assert_eq!(
+		dt.formatl_time_style(TimeStyle::Full, "fa").to_string(),
+		"۰:۳۴:۵۹ (\u{200e}−۰۳:۰۰ گرینویچ)"
+	);
+	assert_eq!(dt.formatl_time_style(TimeStyle::Full, "ar-EG").to_string(), "١٢:٣٤:٥٩ ص غرينتش-٠٣:٠٠");
+}
+
+#[test]
+fn format_gmt_offsets() {
+	let format = |offset, fmt, locale| {
+		let dt = FixedOffset::east_opt(offset).unwrap().with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		dt.formatl(fmt, locale).to_string()
+	};
+	assert_eq!(format(34200, "%EO|%EG", "en"), "GMT+9:30|GMT+09:30");
+	assert_eq!(format(-10800, "%EO|%EG", "en"), "GMT-3|GMT-03:00");
+	assert_eq!(format(34200, "%EO", "fr"), "UTC+9:30");
+	assert_eq!(format(-10800, "%EO", "fr"), "UTC−3");
+	assert_eq!(format(34200, "%EO", "fi"), "UTC+9.30");
+	assert_eq!(format(34200, "%EO", "he"), "GMT\u{200e}+9:30\u{200e}");
+	assert_eq!(format(34200, "%EO", "fa"), "\u{200e}+۹:۳۰ گرینویچ");
+	assert_eq!(format(19800, "%EG", "ar-EG"), "غرينتش+٠٥:٣٠");
+
+	// the zero offset has a name of its own
+	assert_eq!(format(0, "%EO|%EG", "en"), "GMT|GMT");
+	assert_eq!(
+		Utc.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap().formatl("%EO", "fr").to_string(),
+		"UTC"
+	);
+}
+
+#[test]
+fn format_chrono_tz_offsets() {
+	// whether the chrono-tz feature is enabled or not, with the zone only known with it
+	let dt = ::chrono_tz::Australia::Adelaide.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+	let zone = if cfg!(feature = "chrono-tz") { "ACST" } else { "+09:30" };
+	assert_eq!(dt.formatl("%H:%M %Z", "en").to_string(), format!("00:34 {}", zone));
+	assert_eq!(dt.formatl("%A %e %B %Y", "fr").to_string(), "dimanche  8 juillet 2001");
+}
+
+#[cfg(feature = "chrono-tz")]
+mod chrono_tz {
+	use chrono::{TimeZone, Utc};
+	use chrono_lc::{LocaleDate, LocaleDateTz};
+	use chrono_tz::{America, Asia, Australia, Europe};
+
+	#[test]
+	fn format_zone_names() {
+		let dt = Australia::Adelaide.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		assert_eq!(dt.formatl("%H:%M %Z", "en").to_string(), "00:34 ACST");
+		assert_eq!(dt.formatl("%EZ", "en").to_string(), "Australian Central Standard Time");
+		assert_eq!(dt.formatl("%Ev", "en").to_string(), "Australian Central Time");
+		assert_eq!(dt.formatl("%EV", "en").to_string(), "Adelaide");
+		assert_eq!(dt.formatl("%EZ", "fr").to_string(), "heure normale du centre de l’Australie");
+
+		let winter = Europe::Paris.with_ymd_and_hms(2001, 1, 8, 0, 34, 59).unwrap();
+		let summer = Europe::Paris.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		assert_eq!(winter.formatl("%EZ", "fr").to_string(), "heure normale d’Europe centrale");
+		assert_eq!(summer.formatl("%EZ", "fr").to_string(), "heure d’été d’Europe centrale");
+		assert_eq!(summer.formatl("%Ev", "de").to_string(), "Mitteleuropäische Zeit");
+		assert_eq!(summer.formatl("%Z", "fr").to_string(), "CEST");
+
+		let dt = America::Los_Angeles.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		assert_eq!(dt.formatl("%EZ (%Z)", "en").to_string(), "Pacific Daylight Time (PDT)");
+		assert_eq!(dt.formatl("%Ev", "en").to_string(), "Pacific Time");
+		assert_eq!(dt.formatl("%Ev", "ja").to_string(), "米国太平洋時間");
+		assert_eq!(dt.formatl("%EV", "en").to_string(), "Los Angeles");
+	}
+
+	#[test]
+	fn format_zones_of_metazones() {
+		// zones are named after the CLDR metazone they belong to
+		let dt = America::Recife.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		assert_eq!(dt.formatl("%EZ", "en").to_string(), "Brasilia Standard Time");
+		let dt = Europe::Dublin.with_ymd_and_hms(2001, 1, 8, 0, 34, 59).unwrap();
+		assert_eq!(dt.formatl("%Ev", "fr").to_string(), "heure moyenne de Greenwich");
+		let dt = Asia::Kolkata.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		assert_eq!(dt.formatl("%EZ", "ja").to_string(), "インド標準時");
+	}
+
+	#[test]
+	fn format_exemplar_cities() {
+		let format = |dt: ::chrono::DateTime<::chrono_tz::Tz>, locale| dt.formatl("%EV", locale).to_string();
+		let summer = |tz: ::chrono_tz::Tz| tz.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		assert_eq!(format(summer(America::Los_Angeles), "ja"), "ロサンゼルス");
+		assert_eq!(format(summer(America::Los_Angeles), "ja-JP"), "ロサンゼルス");
+		assert_eq!(format(summer(Europe::London), "fr"), "Londres");
+		assert_eq!(format(summer(America::Sao_Paulo), "en"), "São Paulo");
+		assert_eq!(format(summer(America::Sao_Paulo), "de"), "São Paulo");
+
+		// cities the locale doesn't name are written after the identifier of the zone
+		assert_eq!(format(summer(Europe::Paris), "fr"), "Paris");
+		assert_eq!(format(summer(Asia::Kathmandu), "ja"), "Kathmandu");
+	}
+
+	#[test]
+	fn format_zones_without_names() {
+		let dt = Asia::Kathmandu.with_ymd_and_hms(2001, 7, 8, 0, 34, 59).unwrap();
+		assert_eq!(dt.formatl("%EZ|%Ev|%EV", "en").to_string(), "GMT+05:45|GMT+05:45|Kathmandu");
+	}
+
+	#[test]
+	fn format_in_zones() {
+		let dt = Utc.with_ymd_and_hms(2001, 7, 7, 15, 4, 59).unwrap();
+		assert_eq!(
+			dt.formatl_in(Australia::Adelaide, "%A %e %B %H:%M %Z", "fr").to_string(),
+			"dimanche  8 juillet 00:34 ACST"
+		);
+		assert_eq!(
+			dt.formatl_in(America::New_York, "%c (%Ev)", "en").to_string(),
+			"Sat Jul  7 11:04:59 2001 (Eastern Time)"
+		);
+	}
+}
@@ -45,8 +45,8 @@ fn format_tr() {
 	assert_eq!(dt.formatl("%I", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), dt.formatl("%_I", locale).to_string());
-	assert_eq!(dt.formatl("%P", locale).to_string(), "am");
-	assert_eq!(dt.formatl("%p", locale).to_string(), "AM");
+	assert_eq!(dt.formatl("%P", locale).to_string(), "öö");
+	assert_eq!(dt.formatl("%p", locale).to_string(), "ÖÖ");
 	assert_eq!(dt.formatl("%M", locale).to_string(), "34");
 	assert_eq!(dt.formatl("%S", locale).to_string(), "60");
 	assert_eq!(dt.formatl("%f", locale).to_string(), "026490708");
@@ -62,7 +62,7 @@ fn format_tr() {
 	assert_eq!(dt.formatl("%R", locale).to_string(), "00:34");
 	assert_eq!(dt.formatl("%T", locale).to_string(), "00:34:60");
 	assert_eq!(dt.formatl("%X", locale).to_string(), "00:34:60");
-	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 AM");
+	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 ÖÖ");
 
 	// time zone specifiers
 	//assert_eq!(dt.formatl("%Z", locale).to_string(), "ACST");
@@ -128,14 +128,14 @@ fn format_tr_naive() {
 	assert_eq!(dt.formatl("%I", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), "12");
 	assert_eq!(dt.formatl("%l", locale).to_string(), dt.formatl("%_I", locale).to_string());
-	assert_eq!(dt.formatl("%P", locale).to_string(), "am");
-	assert_eq!(dt.formatl("%p", locale).to_string(), "AM");
+	assert_eq!(dt.formatl("%P", locale).to_string(), "öö");
+	assert_eq!(dt.formatl("%p", locale).to_string(), "ÖÖ");
 	assert_eq!(dt.formatl("%M", locale).to_string(), "34");
 	assert_eq!(dt.formatl("%S", locale).to_string(), "60");
 	assert_eq!(dt.formatl("%R", locale).to_string(), "00:34");
 	assert_eq!(dt.formatl("%T", locale).to_string(), "00:34:60");
 	assert_eq!(dt.formatl("%X", locale).to_string(), "00:34:60");
-	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 AM");
+	assert_eq!(dt.formatl("%r", locale).to_string(), "12:34:60 ÖÖ");
 
 	// date & time specifiers
 	assert_eq!(dt.formatl("%c", locale).to_string(), "Paz Tem  8 00:34:60 2001");
@@ -0,0 +1,310 @@
+//! Imports locales from the JSON distribution of the Unicode CLDR (`cldr-dates-full`).
+//!
+//! Each `main/<locale>/ca-gregorian.json` file is converted to a file in the crate's schema.
+//! Names are taken from the stand-alone context, as `%B` and friends are often used on their own,
+//! and CLDR patterns are translated to the strftime syntax understood by `formatl`. The months of
+//! the format context, which CLDR patterns write with a day (e.g. "8 lipca 2001" in Polish), are
+//! imported as well where they differ. Weekdays are only imported stand-alone, as CLDR patterns
+//! write them (e.g. `cccc d. MMMM y` in Finnish), and the AM/PM markers only in the format context.
+//!
+//! The months of the Hebrew, Hijri and Persian calendars and the animals of the Chinese zodiac are
+//! read from the `ca-hebrew.json`, `ca-islamic.json`, `ca-persian.json` and `ca-chinese.json`
+//! files of the locale, when CLDR has them.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::{locale_file_name, write_locale, Error, Locale};
+
+const MONTHS: [&str; 12] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"];
+// Adar II comes last in the crate, after the months of common years
+const HEBREW_MONTHS: [&str; 14] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "7-yeartype-leap"];
+const CALENDARS: [&str; 4] = ["hebrew", "islamic", "persian", "chinese"];
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+const ERAS: [&str; 2] = ["0", "1"];
+const STYLES: [&str; 4] = ["full", "long", "medium", "short"];
+
+/// Converts every locale found in a `cldr-dates-full` checkout and writes them to `out_dir`.
+///
+/// `cldr_dir` can either be the root of the package or its `main` directory. When `only` is not
+/// empty, just the listed locales are converted. Returns the names of the written files.
+pub fn import(cldr_dir: &Path, out_dir: &Path, only: &[String]) -> Result<Vec<String>, Error> {
+	let main = if cldr_dir.join("main").is_dir() {
+		cldr_dir.join("main")
+	} else {
+		cldr_dir.to_path_buf()
+	};
+
+	let mut ids = fs::read_dir(&main)?
+		.filter_map(|entry| entry.ok())
+		.filter(|entry| entry.path().join("ca-gregorian.json").is_file())
+		.map(|entry| entry.file_name().to_string_lossy().into_owned())
+		.filter(|id| id != "root" && id != "und")
+		.filter(|id| only.is_empty() || only.iter().any(|o| locale_file_name(o) == locale_file_name(id)))
+		.collect::<Vec<String>>();
+	ids.sort();
+
+	let mut written = Vec::new();
+	for id in ids {
+		let data = fs::read_to_string(main.join(&id).join("ca-gregorian.json"))?;
+		let mut locale = convert(&data)?;
+		for calendar in CALENDARS {
+			let path = main.join(&id).join(format!("ca-{}.json", calendar));
+			if path.is_file() {
+				convert_calendar(&mut locale, &fs::read_to_string(path)?)?;
+			}
+		}
+		let name = locale_file_name(&id);
+		write_locale(&out_dir.join(format!("{}.json", name)), &locale)?;
+		written.push(name);
+	}
+
+	Ok(written)
+}
+
+/// Converts the contents of a single `ca-gregorian.json` file.
+pub fn convert(data: &str) -> Result<Locale, Error> {
+	let root = serde_json::from_str::<Value>(data)?;
+	let locale = root.get("main").and_then(Value::as_object).and_then(|main| main.values().next());
+	let gregorian = locale
+		.and_then(|locale| locale.pointer("/dates/calendars/gregorian"))
+		.ok_or_else(|| Error::Invalid("missing main/<locale>/dates/calendars/gregorian".to_owned()))?;
+
+	// `%p` is written within patterns, in the format context (e.g. "לפנה״צ" rather than "AM" in Hebrew)
+	let ampm = strings(gregorian.pointer("/dayPeriods/format/abbreviated"), &["am", "pm"]).map(|ampm| {
+		let lower = ampm.iter().map(|s| s.to_lowercase());
+		let upper = ampm.iter().map(|s| s.to_uppercase());
+		lower.chain(upper).collect()
+	});
+
+	let short_months = names(gregorian, "months", "abbreviated", &MONTHS);
+	let long_months = names(gregorian, "months", "wide", &MONTHS);
+
+	Ok(Locale {
+		format_short_months: format_names(gregorian, "months", "abbreviated", &MONTHS, short_months.as_ref()),
+		format_long_months: format_names(gregorian, "months", "wide", &MONTHS, long_months.as_ref()),
+		short_months,
+		long_months,
+		narrow_months: names(gregorian, "months", "narrow", &MONTHS),
+		short_weekdays: names(gregorian, "days", "abbreviated", &WEEKDAYS),
+		long_weekdays: names(gregorian, "days", "wide", &WEEKDAYS),
+		ampm,
+		short_eras: strings(gregorian.pointer("/eras/eraAbbr"), &ERAS),
+		narrow_eras: strings(gregorian.pointer("/eras/eraNarrow"), &ERAS),
+		date_formats: patterns(gregorian.get("dateFormats")),
+		time_formats: patterns(gregorian.get("timeFormats")),
+		date_time_formats: patterns(gregorian.get("dateTimeFormats")),
+		available_formats: available_formats(gregorian),
+		date_interval_fmt: date_intervals(gregorian),
+		time_interval_fmt: time_intervals(gregorian),
+		date_time_interval_fmt: pattern(gregorian.pointer("/dateTimeFormats/medium")),
+		interval_fallback_fmt: pattern(gregorian.pointer("/dateTimeFormats/intervalFormats/intervalFormatFallback")),
+		cldr_version: locale
+			.and_then(|locale| locale.pointer("/identity/version/_cldrVersion"))
+			.and_then(Value::as_str)
+			.map(str::to_owned),
+		..Locale::default()
+	})
+}
+
+/// Adds the names of another calendar from the contents of a `ca-hebrew.json`, `ca-islamic.json`,
+/// `ca-persian.json` or `ca-chinese.json` file. Other calendars are ignored.
+pub fn convert_calendar(locale: &mut Locale, data: &str) -> Result<(), Error> {
+	let root = serde_json::from_str::<Value>(data)?;
+	let calendars = root
+		.get("main")
+		.and_then(Value::as_object)
+		.and_then(|main| main.values().next())
+		.and_then(|locale| locale.pointer("/dates/calendars"))
+		.and_then(Value::as_object)
+		.ok_or_else(|| Error::Invalid("missing main/<locale>/dates/calendars".to_owned()))?;
+
+	for (name, calendar) in calendars {
+		match name.as_str() {
+			"hebrew" => {
+				locale.hebrew_short_months = names(calendar, "months", "abbreviated", &HEBREW_MONTHS);
+				locale.hebrew_long_months = names(calendar, "months", "wide", &HEBREW_MONTHS);
+			}
+			"islamic" => {
+				locale.hijri_short_months = names(calendar, "months", "abbreviated", &MONTHS);
+				locale.hijri_long_months = names(calendar, "months", "wide", &MONTHS);
+			}
+			"persian" => {
+				locale.persian_short_months = names(calendar, "months", "abbreviated", &MONTHS);
+				locale.persian_long_months = names(calendar, "months", "wide", &MONTHS);
+			}
+			"chinese" => locale.chinese_zodiacs = strings(calendar.pointer("/cyclicNameSets/zodiacs/format/abbreviated"), &MONTHS),
+			_ => {}
+		}
+	}
+
+	Ok(())
+}
+
+/// Reads a set of names, preferring the stand-alone context over the format one.
+fn names(calendar: &Value, field: &str, width: &str, keys: &[&str]) -> Option<Vec<String>> {
+	["stand-alone", "format"]
+		.iter()
+		.find_map(|context| strings(calendar.get(field).and_then(|f| f.get(context)).and_then(|c| c.get(width)), keys))
+}
+
+/// Reads a set of names of the format context, if they differ from the stand-alone ones.
+fn format_names(calendar: &Value, field: &str, width: &str, keys: &[&str], stand_alone: Option<&Vec<String>>) -> Option<Vec<String>> {
+	let names = strings(calendar.get(field).and_then(|f| f.get("format")).and_then(|c| c.get(width)), keys)?;
+	Some(names).filter(|names| Some(names) != stand_alone)
+}
+
+fn strings(data: Option<&Value>, keys: &[&str]) -> Option<Vec<String>> {
+	let data = data?;
+	keys.iter().map(|key| data.get(key).and_then(Value::as_str).map(str::to_owned)).collect()
+}
+
+fn patterns(data: Option<&Value>) -> Option<Vec<String>> {
+	strings(data, &STYLES)?.iter().map(|pattern| icu_to_strftime(pattern)).collect()
+}
+
+fn pattern(data: Option<&Value>) -> Option<String> {
+	icu_to_strftime(data?.as_str()?)
+}
+
+/// Reads the patterns of the flexible formats by their skeleton, leaving out the variants (e.g.
+/// `MMMd-alt-variant`) and the patterns that can't be written with strftime. Years next to an era
+/// are years of the era.
+fn available_formats(calendar: &Value) -> Option<BTreeMap<String, String>> {
+	let formats = calendar.pointer("/dateTimeFormats/availableFormats")?.as_object()?;
+	let formats = formats
+		.iter()
+		.filter(|(skeleton, _)| !skeleton.contains('-'))
+		.filter_map(|(skeleton, pattern)| {
+			let pattern = icu_to_strftime(pattern.as_str()?)?;
+			let pattern = if skeleton.contains('G') { pattern.replace("%Y", "%Ey") } else { pattern };
+			Some((skeleton.clone(), pattern))
+		});
+	Some(formats.collect())
+}
+
+/// Reads the patterns of long date intervals: the long date format, then the `yMMMMd` intervals
+/// differing in the year, month and day. Most locales only have the `yMMMd` ones, whose
+/// abbreviated months are widened instead.
+fn date_intervals(calendar: &Value) -> Option<Vec<String>> {
+	let intervals = ["yMMMMd", "yMMMd"].iter().find_map(|skeleton| {
+		strings(
+			calendar.pointer(&format!("/dateTimeFormats/intervalFormats/{}", skeleton)),
+			&["y", "M", "d"],
+		)
+	})?;
+	let intervals = intervals
+		.iter()
+		.map(|interval| icu_to_strftime(interval).map(|interval| interval.replace("%b", "%B")));
+	std::iter::once(pattern(calendar.pointer("/dateFormats/long"))).chain(intervals).collect()
+}
+
+/// Reads the patterns of time intervals in hours and minutes: the short time format, then the
+/// intervals differing in AM/PM, the hour and the minute, on the clock of the short time format.
+fn time_intervals(calendar: &Value) -> Option<Vec<String>> {
+	let short = calendar.pointer("/timeFormats/short")?.as_str()?;
+	let intervals = if has_field(short, 'h') {
+		strings(calendar.pointer("/dateTimeFormats/intervalFormats/hm"), &["a", "h", "m"])?
+	} else {
+		// AM/PM can't differ without the hour doing so as well
+		strings(calendar.pointer("/dateTimeFormats/intervalFormats/Hm"), &["H", "H", "m"])?
+	};
+	let intervals = intervals.iter().map(|interval| icu_to_strftime(interval));
+	std::iter::once(icu_to_strftime(short)).chain(intervals).collect()
+}
+
+/// Returns whether a CLDR pattern has a field, outside of its quoted literals (e.g. the `'h'` of
+/// `HH 'h' mm`). Quotes alternate between fields and literals, as a quote written twice does.
+fn has_field(pattern: &str, field: char) -> bool {
+	pattern.split('\'').step_by(2).any(|fields| fields.contains(field))
+}
+
+/// Translates a CLDR date pattern (e.g. `EEEE d MMMM y`) to strftime (`%A %-d %B %Y`).
+///
+/// Quoted literals are unquoted and the `{0}`/`{1}` placeholders of date-time patterns are kept as
+/// they are. Returns `None` when the pattern uses a field that cannot be expressed with strftime.
+pub fn icu_to_strftime(pattern: &str) -> Option<String> {
+	let mut res = String::new();
+	let mut chars = pattern.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		if c == '\'' {
+			if chars.peek() == Some(&'\'') {
+				chars.next();
+				res.push('\'');
+				continue;
+			}
+			// quoted literal, where '' stands for a single quote
+			while let Some(c) = chars.next() {
+				if c == '\'' {
+					if chars.peek() == Some(&'\'') {
+						chars.next();
+					} else {
+						break;
+					}
+				}
+				push_literal(&mut res, c);
+			}
+		} else if c.is_ascii_alphabetic() {
+			let mut count = 1;
+			while chars.peek() == Some(&c) {
+				chars.next();
+				count += 1;
+			}
+			res.push_str(field_to_strftime(c, count)?);
+		} else {
+			push_literal(&mut res, c);
+		}
+	}
+
+	Some(res)
+}
+
+fn push_literal(res: &mut String, c: char) {
+	if c == '%' {
+		res.push_str("%%");
+	} else {
+		res.push(c);
+	}
+}
+
+fn field_to_strftime(field: char, count: usize) -> Option<&'static str> {
+	let spec = match (field, count) {
+		('G', 1..=3) => "%EC",
+		('G', 5) => "%EN",
+		('y' | 'u', 2) => "%y",
+		('y' | 'u', _) => "%Y",
+		('Y', 2) => "%g",
+		('Y', _) => "%G",
+		('M' | 'L', 1) => "%-m",
+		('M' | 'L', 2) => "%m",
+		('M' | 'L', 3) => "%b",
+		('M' | 'L', 4) => "%B",
+		('d', 1) => "%-d",
+		('d', 2) => "%d",
+		('D', _) => "%j",
+		('E', 1..=3) | ('c' | 'e', 3) => "%a",
+		('E' | 'c' | 'e', 4) => "%A",
+		('a', 1..=3) => "%p",
+		('h', 1) => "%-I",
+		('h', 2) => "%I",
+		('H', 1) => "%-H",
+		('H', 2) => "%H",
+		('m', 1) => "%-M",
+		('m', 2) => "%M",
+		('s', 1) => "%-S",
+		('s', 2) => "%S",
+		('z', 4) => "%EZ",
+		('v', 4) => "%Ev",
+		('z' | 'v', _) => "%Z",
+		('O', 1) => "%EO",
+		('O', 4) => "%EG",
+		('Z', 1..=3) | ('x', 2) => "%z",
+		('Z', 5) | ('x', 3) => "%:z",
+		_ => return None,
+	};
+	Some(spec)
+}
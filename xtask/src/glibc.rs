@@ -0,0 +1,204 @@
+//! Imports locales from glibc's locale definition files (`localedata/locales/*`).
+//!
+//! Only the `LC_TIME` category is read. Names are taken as they are, so the output matches
+//! glibc's `strftime` (e.g. genitive month names in Polish), and the `d_fmt`, `t_fmt`, `d_t_fmt`
+//! and `t_fmt_ampm` patterns are kept for `%x`, `%X`, `%c` and `%r`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::{locale_file_name, write_locale, Error, Locale};
+
+/// Keywords of the `LC_TIME` category and their values.
+pub type LcTime = HashMap<String, Vec<String>>;
+
+/// Converts every locale found in glibc's `localedata/locales` directory and writes them to `out_dir`.
+///
+/// Locales with a modifier (such as `ca_ES@valencia`) and files without an `LC_TIME` category
+/// are skipped. When `only` is not empty, just the listed locales are converted. Returns the names
+/// of the written files.
+pub fn import(locales_dir: &Path, out_dir: &Path, only: &[String]) -> Result<Vec<String>, Error> {
+	let mut ids = fs::read_dir(locales_dir)?
+		.filter_map(|entry| entry.ok())
+		.filter(|entry| entry.path().is_file())
+		.map(|entry| entry.file_name().to_string_lossy().into_owned())
+		.filter(|id| !id.contains('@') && id != "POSIX")
+		.filter(|id| only.is_empty() || only.iter().any(|o| locale_file_name(o) == locale_file_name(id)))
+		.collect::<Vec<String>>();
+	ids.sort();
+
+	let mut written = Vec::new();
+	for id in ids {
+		let lc_time = match load(locales_dir, &id)? {
+			Some(lc_time) => lc_time,
+			None => continue,
+		};
+		let name = locale_file_name(&id);
+		write_locale(&out_dir.join(format!("{}.json", name)), &convert(&lc_time))?;
+		written.push(name);
+	}
+
+	Ok(written)
+}
+
+/// Reads the `LC_TIME` category of a locale, following `copy` directives.
+pub fn load(locales_dir: &Path, id: &str) -> Result<Option<LcTime>, Error> {
+	let source = fs::read_to_string(locales_dir.join(id))?;
+	let mut lc_time = match parse_lc_time(&source) {
+		Some(lc_time) => lc_time,
+		None => return Ok(None),
+	};
+
+	if let Some(copy) = lc_time.remove("copy").and_then(|copy| copy.into_iter().next()) {
+		if copy == id {
+			return Err(Error::Invalid(format!("{} copies itself", id)));
+		}
+		let mut copied = load(locales_dir, &copy)?.ok_or_else(|| Error::Invalid(format!("{} has no LC_TIME to copy", copy)))?;
+		copied.extend(lc_time);
+		lc_time = copied;
+	}
+
+	Ok(Some(lc_time))
+}
+
+/// Converts an `LC_TIME` category to the crate's schema.
+pub fn convert(lc_time: &LcTime) -> Locale {
+	let list = |keyword: &str, len: usize| lc_time.get(keyword).filter(|values| values.len() == len).cloned();
+	// glibc weeks start from Sunday
+	let weekdays = |keyword: &str| {
+		list(keyword, 7).map(|mut days| {
+			days.rotate_left(1);
+			days
+		})
+	};
+	// empty patterns mean that glibc uses the POSIX ones
+	let pattern = |keyword: &str| {
+		lc_time
+			.get(keyword)
+			.and_then(|values| values.first())
+			.map(|pattern| formattable_pattern(pattern))
+			.filter(|pattern| !pattern.is_empty())
+	};
+
+	let ampm = list("am_pm", 2).map(|ampm| {
+		let lower = ampm.iter().map(|s| s.to_lowercase());
+		lower.chain(ampm.iter().cloned()).collect()
+	});
+
+	Locale {
+		short_months: list("abmon", 12),
+		long_months: list("mon", 12),
+		short_weekdays: weekdays("abday"),
+		long_weekdays: weekdays("day"),
+		ampm,
+		d_fmt: pattern("d_fmt"),
+		t_fmt: pattern("t_fmt"),
+		d_t_fmt: pattern("d_t_fmt"),
+		t_fmt_ampm: pattern("t_fmt_ampm"),
+		..Locale::default()
+	}
+}
+
+/// Returns a pattern with only the specifiers chrono_lc formats: unknown specifiers are dropped.
+/// The zone (`%Z` and `%z`) is kept, and left out when formatting dates without an offset. The
+/// alternative digits of `%O` are the locale's numbering system already.
+pub fn formattable_pattern(pattern: &str) -> String {
+	let mut res = String::new();
+	let mut chars = pattern.chars();
+	while let Some(c) = chars.next() {
+		if c != '%' {
+			res.push(c);
+			continue;
+		}
+
+		let mut spec = chars.next();
+		let modifier = match spec {
+			Some(modifier @ ('E' | 'O')) => {
+				spec = chars.next();
+				Some(modifier)
+			}
+			_ => None,
+		};
+		match (modifier, spec) {
+			(Some('E'), Some(spec @ ('C' | 'y' | 'Y'))) => res.extend(['%', 'E', spec]),
+			(_, Some(spec)) if "aAbBcCdDeFgGhHIjklmMnpPrRsStTuUvVwWxXyYzZ%nt".contains(spec) => res.extend(['%', spec]),
+			_ => {}
+		}
+	}
+	res
+}
+
+/// Parses the `LC_TIME` category of a locale definition file, if it has one.
+pub fn parse_lc_time(source: &str) -> Option<LcTime> {
+	let mut comment_char = '#';
+	let mut escape_char = '\\';
+	let mut lc_time = None;
+	let mut line = String::new();
+
+	for raw in source.lines() {
+		// lines ending with the escape character continue on the next one
+		if let Some(raw) = raw.strip_suffix(escape_char) {
+			line.push_str(raw);
+			continue;
+		}
+		line.push_str(raw);
+		let logical = std::mem::take(&mut line);
+		let logical = logical.trim();
+
+		if logical.is_empty() || logical.starts_with(comment_char) {
+			continue;
+		}
+
+		let (keyword, values) = logical.split_once(char::is_whitespace).unwrap_or((logical, ""));
+		let values = values.trim();
+		match (keyword, lc_time.as_mut()) {
+			("comment_char", _) => comment_char = values.chars().next().unwrap_or(comment_char),
+			("escape_char", _) => escape_char = values.chars().next().unwrap_or(escape_char),
+			("LC_TIME", _) => lc_time = Some(LcTime::new()),
+			("END", Some(_)) if values == "LC_TIME" => return lc_time,
+			(keyword, Some(lc_time)) => {
+				lc_time.insert(keyword.to_owned(), parse_values(values, escape_char));
+			}
+			_ => {}
+		}
+	}
+
+	None
+}
+
+/// Parses a `;` separated list of strings, decoding `<Uxxxx>` characters and escapes.
+fn parse_values(values: &str, escape_char: char) -> Vec<String> {
+	let mut res = Vec::new();
+	let mut chars = values.chars();
+	let mut value = String::new();
+	let mut quoted = false;
+
+	while let Some(c) = chars.next() {
+		match c {
+			'"' => quoted = !quoted,
+			';' if !quoted => res.push(std::mem::take(&mut value)),
+			c if c == escape_char => value.extend(chars.next()),
+			'<' => {
+				let name = chars.by_ref().take_while(|&c| c != '>').collect::<String>();
+				let decoded = name
+					.strip_prefix('U')
+					.and_then(|code| u32::from_str_radix(code, 16).ok())
+					.and_then(char::from_u32);
+				match decoded {
+					Some(c) => value.push(c),
+					None => {
+						value.push('<');
+						value.push_str(&name);
+						value.push('>');
+					}
+				}
+			}
+			c if c.is_whitespace() && !quoted => {}
+			c => value.push(c),
+		}
+	}
+	res.push(value);
+
+	res
+}
@@ -0,0 +1,161 @@
+//! Maintenance tasks for `chrono_lc`.
+//!
+//! The importers in this crate convert third party locale databases into the JSON files found in
+//! the `locales` directory, and the metazones of the time zones into `data/metazones.json`, which
+//! `build.rs` compiles into the library.
+
+#[macro_use]
+extern crate serde_derive;
+
+pub mod cldr;
+pub mod glibc;
+pub mod metazones;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{Error as IoError, ErrorKind, Write};
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::ser::{PrettyFormatter, Serializer};
+use serde_json::{Error as JsonError, Map, Value};
+
+/// A locale, in the schema read by `build.rs`.
+///
+/// Weekdays start from Monday, the Hebrew months end with Adar II, the `format_*` months are the
+/// names written with a day where they differ from the stand-alone ones, `ampm` holds the lowercase
+/// then the uppercase forms and patterns are ordered from the longest (`full`) to the shortest
+/// (`short`) style. The `d_fmt`, `t_fmt`, `d_t_fmt` and `t_fmt_ampm` patterns are the ones of `%x`,
+/// `%X`, `%c` and `%r`. Interval patterns are ordered by the greatest field the ends differ in,
+/// starting with the pattern for equal ends, and `available_formats` holds the patterns of the
+/// flexible formats by their skeleton. `cldr_version` is the version of CLDR the locale was
+/// imported from, if it was.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Locale {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub short_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub long_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub narrow_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub format_short_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub format_long_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub short_weekdays: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub long_weekdays: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ampm: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub short_eras: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub narrow_eras: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub hebrew_short_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub hebrew_long_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub hijri_short_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub hijri_long_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub persian_short_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub persian_long_months: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub chinese_zodiacs: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub date_formats: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub time_formats: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub date_time_formats: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub available_formats: Option<BTreeMap<String, String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub date_interval_fmt: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub time_interval_fmt: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub date_time_interval_fmt: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub interval_fallback_fmt: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub d_fmt: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub t_fmt: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub d_t_fmt: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub t_fmt_ampm: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cldr_version: Option<String>,
+}
+
+/// Writes a locale as JSON, indented with tabs like the hand-written files.
+///
+/// An existing file is updated: the keys of the locale replace those of the file where they are,
+/// and the keys the importers don't write (e.g. the ordinals or the names of the zones) are kept.
+pub fn write_locale(path: &Path, locale: &Locale) -> Result<(), Error> {
+	let mut data = match fs::read_to_string(path) {
+		Ok(existing) => serde_json::from_str::<Map<String, Value>>(&existing)?,
+		Err(e) if e.kind() == ErrorKind::NotFound => Map::new(),
+		Err(e) => return Err(e.into()),
+	};
+	if let Value::Object(imported) = serde_json::to_value(locale)? {
+		data.extend(imported);
+	}
+	write_json(path, &data)
+}
+
+/// Writes a value as JSON, indented with tabs like the hand-written files.
+pub fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir)?;
+	}
+
+	let mut out = Vec::new();
+	let mut ser = Serializer::with_formatter(&mut out, PrettyFormatter::with_indent(b"\t"));
+	value.serialize(&mut ser)?;
+	out.push(b'\n');
+
+	File::create(path)?.write_all(&out)?;
+	Ok(())
+}
+
+/// Converts a locale identifier such as `pt_BR` or `sr-Latn` to the name used for the JSON file.
+pub fn locale_file_name(id: &str) -> String {
+	id.to_lowercase().replace('_', "-")
+}
+
+#[derive(Debug)]
+pub enum Error {
+	Io(IoError),
+	Json(JsonError),
+	Invalid(String),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::Io(e) => write!(f, "I/O error: {}", e),
+			Error::Json(e) => write!(f, "JSON error: {}", e),
+			Error::Invalid(e) => write!(f, "invalid input: {}", e),
+		}
+	}
+}
+
+impl From<IoError> for Error {
+	fn from(e: IoError) -> Self {
+		Error::Io(e)
+	}
+}
+
+impl From<JsonError> for Error {
+	fn from(e: JsonError) -> Self {
+		Error::Json(e)
+	}
+}
@@ -0,0 +1,61 @@
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage: cargo xtask <task> [options]
+
+Tasks:
+    cldr <cldr-dates-full dir> [--out <dir>] [--only <locale,...>]
+        Imports the Gregorian calendar data of every CLDR locale into <dir> (default: locales)
+    glibc <localedata/locales dir> [--out <dir>] [--only <locale,...>]
+        Imports the LC_TIME category of every glibc locale into <dir> (default: locales)
+    metazones <cldr dir> [--out <file>]
+        Imports the metazones of the CLDR time zones into <file> (default: data/metazones.json),
+        from the cldr-core and cldr-bcp47 packages, at the CLDR release the file is pinned to (42)
+";
+
+fn main() {
+	let mut args = env::args().skip(1);
+	let task = args.next();
+	let mut source = None;
+	let mut out = None;
+	let mut only = Vec::new();
+
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--out" => out = Some(args.next().map(PathBuf::from).unwrap_or_else(|| usage())),
+			"--only" => only = args.next().unwrap_or_else(|| usage()).split(',').map(str::to_owned).collect(),
+			_ if source.is_none() => source = Some(PathBuf::from(arg)),
+			_ => usage(),
+		}
+	}
+
+	let res = match (task.as_deref(), source) {
+		(Some("cldr"), Some(source)) => {
+			let out = out.unwrap_or_else(|| PathBuf::from("locales"));
+			xtask::cldr::import(&source, &out, &only).map(|written| format!("Wrote {} locales to {}", written.len(), out.display()))
+		}
+		(Some("glibc"), Some(source)) => {
+			let out = out.unwrap_or_else(|| PathBuf::from("locales"));
+			xtask::glibc::import(&source, &out, &only).map(|written| format!("Wrote {} locales to {}", written.len(), out.display()))
+		}
+		(Some("metazones"), Some(source)) => {
+			let out = out.unwrap_or_else(|| PathBuf::from("data/metazones.json"));
+			xtask::metazones::import(&source, &out).map(|written| format!("Wrote {} zones to {}", written, out.display()))
+		}
+		_ => usage(),
+	};
+
+	match res {
+		Ok(message) => println!("{}", message),
+		Err(e) => {
+			eprintln!("{}", e);
+			process::exit(1);
+		}
+	}
+}
+
+fn usage() -> ! {
+	eprint!("{}", USAGE);
+	process::exit(2);
+}
@@ -0,0 +1,117 @@
+//! Imports the metazones of the time zones from the JSON distribution of the Unicode CLDR
+//! (`cldr-core`), which the names of the zones in the locale files belong to.
+//!
+//! The current metazone of each zone is read from `supplemental/metaZones.json`, and names the
+//! zone at every date: the metazones zones had in the past are left out. CLDR identifies
+//! zones by their oldest name (e.g. `Asia/Calcutta`), so the metazones are given to the other
+//! names of the zones too, as listed in `bcp47/timezone.json`. The version of CLDR the metazones
+//! come from is written along with them.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::{write_json, Error};
+
+/// The zone CLDR names on its own rather than by a metazone, and the name of its metazone in the
+/// locale files.
+const UTC: (&str, &str) = ("Etc/UTC", "UTC");
+
+/// The metazones of the zones, in the schema of `data/metazones.json` read by `build.rs`.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Metazones {
+	/// The version of CLDR, e.g. "42".
+	pub cldr_version: String,
+	/// The metazone of each zone, by its IANA identifier.
+	pub zones: BTreeMap<String, String>,
+}
+
+/// Converts the metazones found in a directory holding the `supplemental` directory of `cldr-core`
+/// and, for the other names of the zones, the `bcp47` directory of `cldr-bcp47`, and writes them
+/// to `out`. Returns the number of zones written.
+pub fn import(cldr_dir: &Path, out: &Path) -> Result<usize, Error> {
+	let meta_zones = fs::read_to_string(cldr_dir.join("supplemental").join("metaZones.json"))?;
+	let timezones = match fs::read_to_string(cldr_dir.join("bcp47").join("timezone.json")) {
+		Ok(timezones) => Some(timezones),
+		Err(e) if e.kind() == ErrorKind::NotFound => None,
+		Err(e) => return Err(e.into()),
+	};
+
+	let metazones = convert(&meta_zones, timezones.as_deref())?;
+	write_json(out, &metazones)?;
+	Ok(metazones.zones.len())
+}
+
+/// Converts the contents of `metaZones.json`, and of `timezone.json` for the other names of the
+/// zones, into the metazone of each zone.
+pub fn convert(meta_zones: &str, timezones: Option<&str>) -> Result<Metazones, Error> {
+	let root = serde_json::from_str::<Value>(meta_zones)?;
+	let cldr_version = root
+		.pointer("/supplemental/version/_cldrVersion")
+		.and_then(Value::as_str)
+		.ok_or_else(|| Error::Invalid("missing supplemental/version/_cldrVersion".to_owned()))?;
+	let tree = root
+		.pointer("/supplemental/metaZones/metazoneInfo/timezone")
+		.ok_or_else(|| Error::Invalid("missing supplemental/metaZones/metazoneInfo/timezone".to_owned()))?;
+
+	let mut zones = BTreeMap::new();
+	current_metazones(tree, "", &mut zones);
+	zones.insert(UTC.0.to_owned(), UTC.1.to_owned());
+
+	if let Some(timezones) = timezones {
+		for names in aliases(&serde_json::from_str::<Value>(timezones)?) {
+			if let Some(metazone) = names.iter().find_map(|name| zones.get(name)).cloned() {
+				zones.extend(names.into_iter().map(|name| (name, metazone.clone())));
+			}
+		}
+	}
+	Ok(Metazones {
+		cldr_version: cldr_version.to_owned(),
+		zones,
+	})
+}
+
+/// Walks the tree of zones, e.g. `America` → `Argentina` → `Buenos_Aires`, whose leaves list the
+/// metazones of the zone over time. The current one has no end.
+fn current_metazones(tree: &Value, prefix: &str, zones: &mut BTreeMap<String, String>) {
+	let Some(tree) = tree.as_object() else {
+		return;
+	};
+	for (name, node) in tree {
+		let id = format!("{}{}", prefix, name);
+		match node {
+			Value::Array(periods) => {
+				let metazone = periods
+					.iter()
+					.filter_map(|period| period.get("usesMetazone"))
+					.find(|uses| uses.get("_to").is_none())
+					.and_then(|uses| uses.get("_mzone"))
+					.and_then(Value::as_str);
+				if let Some(metazone) = metazone {
+					zones.insert(id, metazone.to_owned());
+				}
+			}
+			_ => current_metazones(node, &format!("{}/", id), zones),
+		}
+	}
+}
+
+/// Returns the names of each zone of the BCP 47 time zone keys, e.g. `Asia/Calcutta` and
+/// `Asia/Kolkata`.
+fn aliases(root: &Value) -> Vec<Vec<String>> {
+	let Some(keys) = root.pointer("/keyword/u/tz").and_then(Value::as_object) else {
+		return Vec::new();
+	};
+	keys.iter()
+		.filter(|(key, _)| !key.starts_with('_'))
+		.map(|(_, key)| {
+			let names = ["_alias", "_iana"].iter().filter_map(|field| key.get(field).and_then(Value::as_str));
+			let mut names: Vec<String> = names.flat_map(str::split_whitespace).map(str::to_owned).collect();
+			names.dedup();
+			names
+		})
+		.collect()
+}
@@ -0,0 +1,211 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Value};
+use xtask::cldr;
+
+#[test]
+fn convert_fr() {
+	let data = fs::read_to_string("tests/fixtures/cldr/main/fr/ca-gregorian.json").unwrap();
+	let locale = cldr::convert(&data).unwrap();
+
+	assert_eq!(locale.short_months.unwrap()[6], "juil.");
+	assert_eq!(locale.long_months.unwrap()[6], "juillet");
+	// the months are named the same with a day
+	assert_eq!(locale.format_short_months, None);
+	assert_eq!(locale.format_long_months, None);
+	assert_eq!(
+		locale.narrow_months.unwrap(),
+		["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]
+	);
+	assert_eq!(locale.short_weekdays.unwrap(), ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."]);
+	assert_eq!(locale.long_weekdays.unwrap()[6], "dimanche");
+	assert_eq!(locale.ampm.unwrap(), ["am", "pm", "AM", "PM"]);
+	assert_eq!(locale.short_eras.unwrap(), ["av. J.-C.", "ap. J.-C."]);
+	assert_eq!(locale.narrow_eras.unwrap(), ["av. J.-C.", "ap. J.-C."]);
+	assert_eq!(locale.date_formats.unwrap(), ["%A %-d %B %Y", "%-d %B %Y", "%-d %b %Y", "%d/%m/%Y"]);
+	assert_eq!(locale.time_formats.unwrap(), ["%H:%M:%S %EZ", "%H:%M:%S %Z", "%H:%M:%S", "%H:%M"]);
+	assert_eq!(locale.date_time_formats.unwrap(), ["{1} à {0}", "{1} à {0}", "{1}, {0}", "{1} {0}"]);
+	let available_formats = locale.available_formats.unwrap();
+	assert_eq!(available_formats["MMMd"], "%-d %b");
+	assert_eq!(available_formats["Gy"], "%Ey %EC");
+	assert_eq!(available_formats.keys().collect::<Vec<_>>(), ["Gy", "Hm", "MMMd", "yMMM"]);
+	assert_eq!(
+		locale.date_interval_fmt.unwrap(),
+		["%-d %B %Y", "%-d %B %Y – %-d %B %Y", "%-d %B – %-d %B %Y", "%-d–%-d %B %Y"]
+	);
+	assert_eq!(
+		locale.time_interval_fmt.unwrap(),
+		["%H:%M", "%H:%M – %H:%M", "%H:%M – %H:%M", "%H:%M – %H:%M"]
+	);
+	assert_eq!(locale.date_time_interval_fmt.unwrap(), "{1}, {0}");
+	assert_eq!(locale.interval_fallback_fmt.unwrap(), "{0} – {1}");
+	assert_eq!(locale.cldr_version.unwrap(), "42");
+}
+
+#[test]
+fn convert_format_context_months() {
+	let months = |names: [&str; 12]| -> Value {
+		names
+			.iter()
+			.enumerate()
+			.map(|(i, name)| ((i + 1).to_string(), Value::from(*name)))
+			.collect()
+	};
+	let stand_alone = [
+		"styczeń",
+		"luty",
+		"marzec",
+		"kwiecień",
+		"maj",
+		"czerwiec",
+		"lipiec",
+		"sierpień",
+		"wrzesień",
+		"październik",
+		"listopad",
+		"grudzień",
+	];
+	let format = [
+		"stycznia",
+		"lutego",
+		"marca",
+		"kwietnia",
+		"maja",
+		"czerwca",
+		"lipca",
+		"sierpnia",
+		"września",
+		"października",
+		"listopada",
+		"grudnia",
+	];
+	let data = json!({ "main": { "pl": { "dates": { "calendars": { "gregorian": { "months": {
+		"format": { "wide": months(format) },
+		"stand-alone": { "wide": months(stand_alone) },
+	} } } } } } });
+	let locale = cldr::convert(&data.to_string()).unwrap();
+
+	// `%B` alone is stand-alone, and written with a day in the format context
+	assert_eq!(locale.long_months.unwrap()[6], "lipiec");
+	assert_eq!(locale.format_long_months.unwrap()[6], "lipca");
+}
+
+#[test]
+fn convert_format_context_ampm() {
+	let data = json!({ "main": { "he": { "dates": { "calendars": { "gregorian": { "dayPeriods": {
+		"format": { "abbreviated": { "am": "לפנה״צ", "pm": "אחה״צ" } },
+		"stand-alone": { "abbreviated": { "am": "AM", "pm": "PM" } },
+	} } } } } } });
+	let locale = cldr::convert(&data.to_string()).unwrap();
+
+	// `%p` is written within patterns
+	assert_eq!(locale.ampm.unwrap(), ["לפנה״צ", "אחה״צ", "לפנה״צ", "אחה״צ"]);
+}
+
+#[test]
+fn convert_wide_date_intervals() {
+	let data = json!({ "main": { "es": { "dates": { "calendars": { "gregorian": {
+		"dateFormats": { "long": "d 'de' MMMM 'de' y" },
+		"dateTimeFormats": { "intervalFormats": {
+			"yMMMd": { "d": "d–d MMM y", "M": "d MMM – d MMM y", "y": "d MMM y – d MMM y" },
+			"yMMMMd": { "d": "d–d 'de' MMMM 'de' y", "M": "d 'de' MMMM – d 'de' MMMM 'de' y", "y": "d 'de' MMMM 'de' y – d 'de' MMMM 'de' y" },
+		} },
+	} } } } } });
+	let locale = cldr::convert(&data.to_string()).unwrap();
+
+	// the intervals of wide months are preferred to widening the abbreviated ones
+	assert_eq!(
+		locale.date_interval_fmt.unwrap(),
+		[
+			"%-d de %B de %Y",
+			"%-d de %B de %Y – %-d de %B de %Y",
+			"%-d de %B – %-d de %B de %Y",
+			"%-d–%-d de %B de %Y"
+		]
+	);
+}
+
+#[test]
+fn convert_time_intervals_of_quoted_patterns() {
+	// the quoted "h" of Canadian French is a literal, not the hour of a 12-hour clock
+	let data = json!({ "main": { "fr-CA": { "dates": { "calendars": { "gregorian": {
+		"timeFormats": { "short": "HH 'h' mm" },
+		"dateTimeFormats": { "intervalFormats": {
+			"hm": { "a": "h 'h' mm a – h 'h' mm a", "h": "h 'h' mm – h 'h' mm a", "m": "h 'h' mm – h 'h' mm a" },
+			"Hm": { "H": "HH 'h' mm – HH 'h' mm", "m": "HH 'h' mm – HH 'h' mm" },
+		} },
+	} } } } } });
+	let locale = cldr::convert(&data.to_string()).unwrap();
+	assert_eq!(
+		locale.time_interval_fmt.unwrap(),
+		["%H h %M", "%H h %M – %H h %M", "%H h %M – %H h %M", "%H h %M – %H h %M"]
+	);
+}
+
+#[test]
+fn convert_calendar() {
+	let zodiacs = ["子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥"];
+	let zodiacs: Value = zodiacs
+		.iter()
+		.enumerate()
+		.map(|(i, name)| ((i + 1).to_string(), Value::from(*name)))
+		.collect();
+	let data = json!({ "main": { "ja": { "dates": { "calendars": { "chinese": {
+		"cyclicNameSets": { "zodiacs": { "format": { "abbreviated": zodiacs } } },
+	} } } } } });
+	let mut locale = xtask::Locale::default();
+	cldr::convert_calendar(&mut locale, &data.to_string()).unwrap();
+	assert_eq!(locale.chinese_zodiacs.unwrap()[5], "巳");
+
+	let months = json!({
+		"1": "תשרי", "2": "חשוון", "3": "כסלו", "4": "טבת", "5": "שבט", "6": "אדר א׳", "7": "אדר",
+		"7-yeartype-leap": "אדר ב׳", "8": "ניסן", "9": "אייר", "10": "סיוון", "11": "תמוז", "12": "אב", "13": "אלול",
+	});
+	let data = json!({ "main": { "he": { "dates": { "calendars": { "hebrew": {
+		"months": { "format": { "wide": months } },
+	} } } } } });
+	let mut locale = xtask::Locale::default();
+	cldr::convert_calendar(&mut locale, &data.to_string()).unwrap();
+
+	// Adar II comes after Elul
+	let months = locale.hebrew_long_months.unwrap();
+	assert_eq!(months[12], "אלול");
+	assert_eq!(months[13], "אדר ב׳");
+	assert_eq!(locale.hebrew_short_months, None);
+}
+
+#[test]
+fn icu_to_strftime() {
+	assert_eq!(cldr::icu_to_strftime("EEE, MMM d, y").unwrap(), "%a, %b %-d, %Y");
+	assert_eq!(cldr::icu_to_strftime("h:mm a").unwrap(), "%-I:%M %p");
+	assert_eq!(cldr::icu_to_strftime("d 'de' MMMM 'de' y").unwrap(), "%-d de %B de %Y");
+	assert_eq!(cldr::icu_to_strftime("HH 'h' mm '100%'").unwrap(), "%H h %M 100%%");
+	assert_eq!(cldr::icu_to_strftime("h 'o''clock' a").unwrap(), "%-I o'clock %p");
+	assert_eq!(cldr::icu_to_strftime("GGGGG y/MM/dd").unwrap(), "%EN %Y/%m/%d");
+	assert_eq!(cldr::icu_to_strftime("HH:mm zzzz (O)").unwrap(), "%H:%M %EZ (%EO)");
+	assert_eq!(cldr::icu_to_strftime("QQQ y"), None);
+}
+
+#[test]
+fn import() {
+	let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cldr");
+	let written = cldr::import(Path::new("tests/fixtures/cldr"), &out, &[]).unwrap();
+	assert_eq!(written, ["fr"]);
+
+	let json = fs::read_to_string(out.join("fr.json")).unwrap();
+	assert!(json.starts_with("{\n\t\"short_months\": [\n\t\t\"janv.\","));
+}
+
+#[test]
+fn import_into_existing_files() {
+	let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cldr-existing");
+	fs::create_dir_all(&out).unwrap();
+	fs::write(out.join("fr.json"), "{\n\t\"ordinal_day_fmt\": \"%-der\",\n\t\"long_months\": []\n}\n").unwrap();
+	cldr::import(Path::new("tests/fixtures/cldr"), &out, &[]).unwrap();
+
+	// the imported keys replace those of the file where they are, and the others are kept
+	let json = fs::read_to_string(out.join("fr.json")).unwrap();
+	assert!(json.starts_with("{\n\t\"ordinal_day_fmt\": \"%-der\",\n\t\"long_months\": [\n\t\t\"janvier\","));
+	assert!(json.contains("\"short_months\""));
+}
@@ -0,0 +1,55 @@
+use std::fs;
+use std::path::Path;
+
+use xtask::glibc;
+
+#[test]
+fn convert_fr() {
+	let lc_time = glibc::load(Path::new("tests/fixtures/glibc"), "fr_FR").unwrap().unwrap();
+	let locale = glibc::convert(&lc_time);
+
+	assert_eq!(locale.short_months.unwrap()[1], "févr.");
+	assert_eq!(locale.long_months.unwrap()[7], "août");
+	assert_eq!(locale.short_weekdays.unwrap(), ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."]);
+	assert_eq!(locale.long_weekdays.unwrap()[6], "dimanche");
+	assert_eq!(locale.ampm.unwrap(), ["", "", "", ""]);
+	assert_eq!(locale.d_fmt.unwrap(), "%d/%m/%Y");
+	assert_eq!(locale.t_fmt.unwrap(), "%T");
+	assert_eq!(locale.d_t_fmt.unwrap(), "%a %d %b %Y %T");
+	assert_eq!(locale.t_fmt_ampm, None);
+}
+
+#[test]
+fn formattable_patterns() {
+	// the zone is left out when formatting dates without an offset
+	assert_eq!(glibc::formattable_pattern("%a %d %b %Y %T %Z"), "%a %d %b %Y %T %Z");
+	assert_eq!(glibc::formattable_pattern("%H:%M %Oz"), "%H:%M %z");
+	assert_eq!(glibc::formattable_pattern("%EY%m月%Od日"), "%EY%m月%d日");
+	assert_eq!(glibc::formattable_pattern("%Ec %Ex %+ %Q"), "%c %x  ");
+}
+
+#[test]
+fn copy() {
+	let dir = Path::new("tests/fixtures/glibc");
+	assert_eq!(glibc::load(dir, "fr_LU").unwrap(), glibc::load(dir, "fr_FR").unwrap());
+	assert_eq!(glibc::load(dir, "i18n").unwrap(), None);
+}
+
+#[test]
+fn parse_lc_time() {
+	let source = "comment_char %\nescape_char /\n% a comment\nLC_TIME\nabday \"a<U00E9>\";/\n   \"b//c\"\nweek 7;19971130;4\nEND LC_TIME\n";
+	let lc_time = glibc::parse_lc_time(source).unwrap();
+
+	assert_eq!(lc_time["abday"], ["aé", "b/c"]);
+	assert_eq!(lc_time["week"], ["7", "19971130", "4"]);
+}
+
+#[test]
+fn import() {
+	let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("glibc");
+	let written = glibc::import(Path::new("tests/fixtures/glibc"), &out, &[]).unwrap();
+	assert_eq!(written, ["fr-fr", "fr-lu"]);
+
+	let json = fs::read_to_string(out.join("fr-lu.json")).unwrap();
+	assert!(json.contains("\t\"d_fmt\": \"%d/%m/%Y\",\n"));
+}
@@ -0,0 +1,46 @@
+use std::fs;
+use std::path::Path;
+
+use xtask::metazones;
+
+#[test]
+fn convert_metazones() {
+	let meta_zones = fs::read_to_string("tests/fixtures/cldr/supplemental/metaZones.json").unwrap();
+	let metazones = metazones::convert(&meta_zones, None).unwrap();
+	assert_eq!(metazones.cldr_version, "42");
+
+	let zones = metazones.zones;
+
+	assert_eq!(zones["America/Los_Angeles"], "America_Pacific");
+	assert_eq!(zones["America/Buenos_Aires"], "Argentina");
+	// the current metazone of zones which changed theirs, and none for zones which left theirs
+	assert_eq!(zones["America/Argentina/San_Luis"], "Argentina");
+	assert!(!zones.contains_key("Africa/Casablanca"));
+	// UTC is named as a zone rather than a metazone in CLDR
+	assert_eq!(zones["Etc/UTC"], "UTC");
+	assert!(!zones.contains_key("Asia/Kolkata"));
+}
+
+#[test]
+fn convert_metazones_of_aliases() {
+	let meta_zones = fs::read_to_string("tests/fixtures/cldr/supplemental/metaZones.json").unwrap();
+	let timezones = fs::read_to_string("tests/fixtures/cldr/bcp47/timezone.json").unwrap();
+	let zones = metazones::convert(&meta_zones, Some(&timezones)).unwrap().zones;
+
+	assert_eq!(zones["Asia/Calcutta"], "India");
+	assert_eq!(zones["Asia/Kolkata"], "India");
+	assert_eq!(zones["America/Argentina/Buenos_Aires"], "Argentina");
+	assert_eq!(zones["US/Pacific"], "America_Pacific");
+	assert_eq!(zones["Etc/Zulu"], "UTC");
+	assert_eq!(zones.len(), 16);
+}
+
+#[test]
+fn import_metazones() {
+	let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("metazones.json");
+	let written = metazones::import(Path::new("tests/fixtures/cldr"), &out).unwrap();
+	assert_eq!(written, 16);
+
+	let json = fs::read_to_string(out).unwrap();
+	assert!(json.starts_with("{\n\t\"cldr_version\": \"42\",\n\t\"zones\": {\n\t\t\"America/Argentina/Buenos_Aires\": \"Argentina\",\n"));
+}